@@ -6,47 +6,45 @@ use itertools::Itertools;
 aoc!(Day1);
 
 impl Solution<Day1> for Day1 {
-    type Parsed = (Vec<u64>, Vec<u64>);
+    type Parsed<'input> = (Vec<u64>, Vec<u64>);
 
-    type Answer = u64;
+    type AnswerA = u64;
 
-    const SAMPLE_ANSWER_A: Self::Answer = 11;
+    type AnswerB = u64;
 
-    const SAMPLE_ANSWER_B: Self::Answer = 31;
+    type Config = ();
 
-    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
+    const CONFIG: Self::Config = ();
+
+    const SAMPLE_CONFIG: Self::Config = ();
+
+    const SAMPLE_ANSWER_A: Self::AnswerA = 11;
+
+    const SAMPLE_ANSWER_B: Self::AnswerB = 31;
+
+    fn parse(input: &str) -> ParseResult<Self::Parsed<'_>> {
         let mut a = Vec::new();
         let mut b = Vec::new();
-        input.lines().for_each(|line| {
+        for line in input.lines() {
             let mut split = line.split("   ");
-            a.push(
-                split
-                    .next()
-                    .expect("No first number")
-                    .parse()
-                    .expect("Could not parse"),
-            );
-            b.push(
-                split
-                    .next()
-                    .expect("No second number")
-                    .parse()
-                    .expect("Could not parse"),
-            );
-        });
+            let first = parse::next(input, line, &mut split, "a first number")?;
+            a.push(parse::number(input, first)?);
+            let second = parse::next(input, line, &mut split, "a second number")?;
+            b.push(parse::number(input, second)?);
+        }
         Ok((a, b))
     }
 
-    fn part_a(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
+    fn part_a(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerA> {
         let (a, b) = input;
         let sorted_a = a.iter().sorted();
         Ok(sorted_a
             .zip(b.iter().sorted())
-            .map(|(a, b)| a.abs_diff(*b) as u64)
+            .map(|(a, b)| a.abs_diff(*b))
             .sum())
     }
 
-    fn part_b(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
+    fn part_b(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerB> {
         let (a, b) = input;
         let mut occurences_map = HashMap::new();
         b.iter().for_each(|&val| {
@@ -1,77 +1,72 @@
 use std::collections::{HashSet, VecDeque};
 
 use aoc_2024::*;
-use dimensions_2::{unsigned::Point, Direction};
+use dimensions_2::{grid::Grid, unsigned::Point};
+use parse::Reason;
 
 aoc!(Day10);
 
 impl Solution<Self> for Day10 {
-    type Parsed = Vec<Vec<u8>>;
+    type Parsed<'input> = Grid<u8>;
 
-    type Answer = usize;
+    type AnswerA = usize;
 
-    const SAMPLE_ANSWER_A: Self::Answer = 36;
+    type AnswerB = usize;
 
-    const SAMPLE_ANSWER_B: Self::Answer = 81;
+    type Config = ();
 
-    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
-        Ok(input
-            .lines()
-            .map(|line| {
-                line.chars()
-                    .map(|ch| ch.to_digit(10).unwrap() as u8)
-                    .collect()
+    const CONFIG: Self::Config = ();
+
+    const SAMPLE_CONFIG: Self::Config = ();
+
+    const SAMPLE_ANSWER_A: Self::AnswerA = 36;
+
+    const SAMPLE_ANSWER_B: Self::AnswerB = 81;
+
+    fn parse(input: &str) -> ParseResult<Self::Parsed<'_>> {
+        let rows = parse::chars(input)
+            .map(|(_, line)| {
+                line.map(|(_, ch, text)| match ch.to_digit(10) {
+                    Some(height) => Ok(height as u8),
+                    None => Err(ParseError::at(input, text, Reason::Invalid("a height"))),
+                })
+                .collect::<ParseResult<Vec<_>>>()
             })
-            .collect())
+            .collect::<ParseResult<Vec<_>>>()?;
+        Ok(Grid::from_rows(rows)?)
     }
 
-    fn part_a(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
-        let trailheads = find_trailheads(&input);
+    fn part_a(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerA> {
+        let trailheads = find_trailheads(input);
 
         let total_score: usize = trailheads
             .iter()
-            .map(|&trailhead| score_trailhead(&input, trailhead))
+            .map(|&trailhead| score_trailhead(input, trailhead))
             .sum();
         Ok(total_score)
     }
 
-    fn part_b(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
-        let trailheads = find_trailheads(&input);
+    fn part_b(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerB> {
+        let trailheads = find_trailheads(input);
 
         let total_rating: usize = trailheads
             .iter()
-            .map(|&trailhead| rating_trailhead(&input, trailhead))
+            .map(|&trailhead| rating_trailhead(input, trailhead))
             .sum();
 
         Ok(total_rating)
     }
 }
 
-fn find_trailheads(map: &[Vec<u8>]) -> Vec<Point> {
-    let mut trailheads = Vec::new();
-    for (i, row) in map.iter().enumerate() {
-        for (j, &height) in row.iter().enumerate() {
-            if height == 0 {
-                trailheads.push(Point::new(i, j));
-            }
-        }
-    }
-    trailheads
+fn find_trailheads(map: &Grid<u8>) -> Vec<Point> {
+    map.positions_of(&0).collect()
 }
 
-fn is_valid_move(map: &[Vec<u8>], current: Point, next: Point) -> bool {
-    let (rows, cols) = (map.len(), map[0].len());
-    let (x, y) = next.get();
-    if x >= rows || y >= cols {
-        return false;
-    }
-    let (cur_x, cur_y) = current.get();
-    let current_height = map[cur_x][cur_y];
-    let next_height = map[x][y];
-    next_height == current_height + 1
+fn is_valid_move(map: &Grid<u8>, current: Point, next: Point) -> bool {
+    map[next] == map[current] + 1
 }
 
-fn score_trailhead(map: &[Vec<u8>], start: Point) -> usize {
+fn score_trailhead(map: &Grid<u8>, start: Point) -> usize {
     let mut visited = HashSet::new();
     let mut queue = VecDeque::new();
     let mut reachable_nines = HashSet::new();
@@ -80,23 +75,12 @@ fn score_trailhead(map: &[Vec<u8>], start: Point) -> usize {
     visited.insert(start);
 
     while let Some(current) = queue.pop_front() {
-        let (x, y) = current.get();
-        let current_height = map[x][y];
-
-        if current_height == 9 {
+        if map[current] == 9 {
             reachable_nines.insert(current);
             continue;
         }
 
-        let directions = [
-            Direction::Up.delta(),
-            Direction::Down.delta(),
-            Direction::Left.delta(),
-            Direction::Right.delta(),
-        ];
-
-        for &dir in &directions {
-            let next = current + dir;
+        for next in map.neighbors4(current) {
             if is_valid_move(map, current, next) && !visited.contains(&next) {
                 visited.insert(next);
                 queue.push_back(next);
@@ -107,7 +91,7 @@ fn score_trailhead(map: &[Vec<u8>], start: Point) -> usize {
     reachable_nines.len()
 }
 
-fn rating_trailhead(map: &[Vec<u8>], start: Point) -> usize {
+fn rating_trailhead(map: &Grid<u8>, start: Point) -> usize {
     let mut visited = HashSet::new();
     visited.insert(start);
     let mut trails = 0;
@@ -115,24 +99,13 @@ fn rating_trailhead(map: &[Vec<u8>], start: Point) -> usize {
     trails
 }
 
-fn count_trails(map: &[Vec<u8>], current: Point, visited: &mut HashSet<Point>, trails: &mut usize) {
-    let (x, y) = current.get();
-    let current_height = map[x][y];
-
-    if current_height == 9 {
+fn count_trails(map: &Grid<u8>, current: Point, visited: &mut HashSet<Point>, trails: &mut usize) {
+    if map[current] == 9 {
         *trails += 1;
         return;
     }
 
-    let directions = [
-        Direction::Up.delta(),
-        Direction::Down.delta(),
-        Direction::Left.delta(),
-        Direction::Right.delta(),
-    ];
-
-    for &dir in &directions {
-        let next = current + dir;
+    for next in map.neighbors4(current) {
         if is_valid_move(map, current, next) && !visited.contains(&next) {
             visited.insert(next);
             count_trails(map, next, visited, trails);
@@ -1,42 +1,56 @@
 use std::collections::HashMap;
 
-use anyhow::Context;
 use aoc_2024::*;
 use itertools::Itertools;
 
 aoc!(Day11);
 
+/// How many times the stones blink in each part.
+#[derive(Debug)]
+struct Blinks {
+    a: usize,
+    b: usize,
+}
+
 impl Solution<Self> for Day11 {
-    type Parsed = Vec<u64>;
+    type Parsed<'input> = Vec<u64>;
+
+    type AnswerA = usize;
+
+    type AnswerB = usize;
+
+    type Config = Blinks;
+
+    const CONFIG: Self::Config = Blinks { a: 25, b: 75 };
 
-    type Answer = usize;
+    const SAMPLE_CONFIG: Self::Config = Self::CONFIG;
 
-    const SAMPLE_ANSWER_A: Self::Answer = 55312;
+    const SAMPLE_ANSWER_A: Self::AnswerA = 55312;
 
-    const SAMPLE_ANSWER_B: Self::Answer = 65601038650482;
+    const SAMPLE_ANSWER_B: Self::AnswerB = 65601038650482;
 
-    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
+    fn parse(input: &str) -> ParseResult<Self::Parsed<'_>> {
         input
             .split_whitespace()
-            .map(|num| num.parse::<u64>().context("Could not parse int"))
+            .map(|num| parse::number(input, num))
             .collect()
     }
 
-    fn part_a(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
-        let mut stone_counts: HashMap<u64, usize> = input.into_iter().counts_by(|stone| stone);
-        for _ in 0..25 {
-            transform_stones(&mut stone_counts);
-        }
-        Ok(stone_counts.values().sum())
+    fn part_a(input: &Self::Parsed<'_>, config: &Self::Config) -> anyhow::Result<Self::AnswerA> {
+        Ok(count_after_blinks(input, config.a))
     }
 
-    fn part_b(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
-        let mut stone_counts: HashMap<u64, usize> = input.into_iter().counts_by(|stone| stone);
-        for _ in 0..75 {
-            transform_stones(&mut stone_counts);
-        }
-        Ok(stone_counts.values().sum())
+    fn part_b(input: &Self::Parsed<'_>, config: &Self::Config) -> anyhow::Result<Self::AnswerB> {
+        Ok(count_after_blinks(input, config.b))
+    }
+}
+
+fn count_after_blinks(stones: &[u64], blinks: usize) -> usize {
+    let mut stone_counts: HashMap<u64, usize> = stones.iter().copied().counts_by(|stone| stone);
+    for _ in 0..blinks {
+        transform_stones(&mut stone_counts);
     }
+    stone_counts.values().sum()
 }
 
 fn transform_stones(stones_counts: &mut HashMap<u64, usize>) {
@@ -1,35 +1,40 @@
 use std::collections::HashSet;
 
 use aoc_2024::*;
-use dimensions_2::{unsigned::Point, Direction};
+use dimensions_2::{grid::Grid, unsigned::Point, Direction};
 use itertools::Itertools;
 
 aoc!(Day12);
 
 impl Solution<Self> for Day12 {
-    type Parsed = Vec<Vec<char>>;
+    type Parsed<'input> = Grid<u8, &'input [u8]>;
 
-    type Answer = usize;
+    type AnswerA = usize;
 
-    const SAMPLE_ANSWER_A: Self::Answer = 140;
+    type AnswerB = usize;
 
-    const SAMPLE_ANSWER_B: Self::Answer = 80;
+    type Config = ();
 
-    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
-        Ok(input
-            .lines()
-            .map(|line| line.chars().into_iter().collect())
-            .collect())
+    const CONFIG: Self::Config = ();
+
+    const SAMPLE_CONFIG: Self::Config = ();
+
+    const SAMPLE_ANSWER_A: Self::AnswerA = 140;
+
+    const SAMPLE_ANSWER_B: Self::AnswerB = 80;
+
+    fn parse(input: &str) -> ParseResult<Self::Parsed<'_>> {
+        Ok(Grid::from_lines(input)?)
     }
 
-    fn part_a(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
+    fn part_a(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerA> {
         Ok(calculate_regions(input, count_area_and_perimeter)
             .into_iter()
             .map(|(_letter, area, perimeter)| area * perimeter)
             .sum())
     }
 
-    fn part_b(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
+    fn part_b(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerB> {
         Ok(calculate_regions(input, count_area_and_sides)
             .into_iter()
             .map(|(_letter, area, sides)| area * sides)
@@ -37,23 +42,17 @@ impl Solution<Self> for Day12 {
     }
 }
 
-fn calculate_regions(
-    grid: Vec<Vec<char>>,
-    count: fn(&Vec<Vec<char>>, &mut Vec<Vec<bool>>, Point) -> (usize, usize),
-) -> Vec<(char, usize, usize)> {
-    let rows = grid.len();
-    let cols = grid[0].len();
-    let mut visited = vec![vec![false; cols]; rows];
+type CountRegion = fn(&Grid<u8, &[u8]>, &mut Grid<bool>, Point) -> (usize, usize);
+
+fn calculate_regions(grid: &Grid<u8, &[u8]>, count: CountRegion) -> Vec<(u8, usize, usize)> {
+    let mut visited = Grid::new(grid.dimensions(), false);
     let mut regions = Vec::new();
 
     // Iterate through the grid to find regions
-    for r in 0..rows {
-        for c in 0..cols {
-            if !visited[r][c] {
-                let letter = grid[r][c];
-                let (area, perimeter) = count(&grid, &mut visited, Point::new(c, r));
-                regions.push((letter, area, perimeter));
-            }
+    for (point, &letter) in grid.iter() {
+        if !visited[point] {
+            let (area, perimeter) = count(grid, &mut visited, point);
+            regions.push((letter, area, perimeter));
         }
     }
 
@@ -61,36 +60,30 @@ fn calculate_regions(
 }
 
 fn count_area_and_sides(
-    grid: &Vec<Vec<char>>,
-    visited: &mut Vec<Vec<bool>>,
+    grid: &Grid<u8, &[u8]>,
+    visited: &mut Grid<bool>,
     start: Point,
 ) -> (usize, usize) {
-    let rows = grid.len();
-    let cols = grid[0].len();
     let mut stack = vec![start];
     let mut area = 0;
     let mut sides: HashSet<(Direction, Point)> = HashSet::new();
     let mut sides_count = 0;
 
-    let letter = grid[start.get().1][start.get().0];
-    visited[start.get().1][start.get().0] = true;
+    let letter = grid[start];
+    visited[start] = true;
 
     while let Some(cur_point) = stack.pop() {
         area += 1;
 
         for direction in Direction::iter() {
-            let (nx, ny) = (cur_point + direction.delta()).get();
-            if ny < rows && nx < cols {
-                if grid[ny][nx] == letter {
-                    if !visited[ny][nx] {
-                        visited[ny][nx] = true;
-                        stack.push(cur_point + direction.delta());
-                    }
-                } else {
-                    sides.insert((direction, cur_point + direction.delta()));
+            let new_point = cur_point + direction.delta();
+            if grid.get(new_point) == Some(&letter) {
+                if !visited[new_point] {
+                    visited[new_point] = true;
+                    stack.push(new_point);
                 }
             } else {
-                sides.insert((direction, cur_point + direction.delta()));
+                sides.insert((direction, new_point));
             }
         }
     }
@@ -100,12 +93,12 @@ fn count_area_and_sides(
         let vec = edges
             .get(&dir)
             .unwrap()
-            .into_iter()
+            .iter()
             .map(|tuple| tuple.1)
             .collect_vec();
         match dir {
             Direction::Up | Direction::Down => {
-                let (mut prev_x, mut prev_y) = (0usize, 0usize);
+                let mut prev: Option<(usize, usize)> = None;
                 vec.iter()
                     .sorted_by(|a, b| match a.get().1.cmp(&b.get().1) {
                         std::cmp::Ordering::Less => std::cmp::Ordering::Less,
@@ -113,17 +106,18 @@ fn count_area_and_sides(
                         std::cmp::Ordering::Greater => std::cmp::Ordering::Greater,
                     })
                     .for_each(|point| {
-                        if point.get().1 != prev_y {
-                            sides_count += 1;
-                        } else if prev_x.abs_diff(point.get().0) > 1 {
+                        let continues = prev.is_some_and(|(prev_x, prev_y)| {
+                            point.get().1 == prev_y && prev_x.abs_diff(point.get().0) == 1
+                        });
+                        if !continues {
                             sides_count += 1;
                         }
-                        (prev_x, prev_y) = point.get();
+                        prev = Some(point.get());
                     });
             }
 
             Direction::Left | Direction::Right => {
-                let (mut prev_x, mut prev_y) = (0usize, 0usize);
+                let mut prev: Option<(usize, usize)> = None;
                 vec.iter()
                     .sorted_by(|a, b| match a.get().0.cmp(&b.get().0) {
                         std::cmp::Ordering::Less => std::cmp::Ordering::Less,
@@ -131,12 +125,13 @@ fn count_area_and_sides(
                         std::cmp::Ordering::Greater => std::cmp::Ordering::Greater,
                     })
                     .for_each(|point| {
-                        if point.get().0 != prev_x {
-                            sides_count += 1;
-                        } else if prev_y.abs_diff(point.get().1) > 1 {
+                        let continues = prev.is_some_and(|(prev_x, prev_y)| {
+                            point.get().0 == prev_x && prev_y.abs_diff(point.get().1) == 1
+                        });
+                        if !continues {
                             sides_count += 1;
                         }
-                        (prev_x, prev_y) = point.get();
+                        prev = Some(point.get());
                     });
             }
         }
@@ -146,18 +141,16 @@ fn count_area_and_sides(
 }
 
 fn count_area_and_perimeter(
-    grid: &Vec<Vec<char>>,
-    visited: &mut Vec<Vec<bool>>,
+    grid: &Grid<u8, &[u8]>,
+    visited: &mut Grid<bool>,
     start: Point,
 ) -> (usize, usize) {
-    let rows = grid.len();
-    let cols = grid[0].len();
     let mut stack = vec![start];
     let mut area = 0;
     let mut perimeter = 0;
 
-    let letter = grid[start.get().1][start.get().0];
-    visited[start.get().1][start.get().0] = true;
+    let letter = grid[start];
+    visited[start] = true;
 
     while let Some(cur_point) = stack.pop() {
         area += 1;
@@ -166,13 +159,10 @@ fn count_area_and_perimeter(
         for direction in Direction::iter() {
             let new_point = cur_point + direction.delta();
 
-            if new_point.get().1 < rows && new_point.get().0 < cols {
-                let (nx, ny) = new_point.get();
-                if grid[ny][nx] == letter && !visited[ny][nx] {
-                    visited[ny][nx] = true;
+            if grid.get(new_point) == Some(&letter) {
+                if !visited[new_point] {
+                    visited[new_point] = true;
                     stack.push(new_point);
-                } else if grid[ny][nx] != letter {
-                    perimeter += 1;
                 }
             } else {
                 perimeter += 1;
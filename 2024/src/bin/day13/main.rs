@@ -1,8 +1,5 @@
-use std::isize;
-
-use anyhow::Context;
 use aoc_2024::*;
-use dimensions_2::unsigned::Point;
+use dimensions_2::{signed::Vector, unsigned::Point};
 
 aoc!(Day13);
 
@@ -14,51 +11,43 @@ struct Entry {
 }
 
 impl Solution<Self> for Day13 {
-    type Parsed = Vec<Entry>;
+    type Parsed<'input> = Vec<Entry>;
+
+    type AnswerA = isize;
+
+    type AnswerB = isize;
+
+    type Config = ();
 
-    type Answer = isize;
+    const CONFIG: Self::Config = ();
 
-    const SAMPLE_ANSWER_A: Self::Answer = 480;
+    const SAMPLE_CONFIG: Self::Config = ();
 
-    const SAMPLE_ANSWER_B: Self::Answer = 875318608908;
+    const SAMPLE_ANSWER_A: Self::AnswerA = 480;
 
-    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
+    const SAMPLE_ANSWER_B: Self::AnswerB = 875318608908;
+
+    fn parse(input: &str) -> ParseResult<Self::Parsed<'_>> {
         let mut entries = Vec::new();
 
         for chunk in input.split("\n\n") {
             let mut lines = chunk.lines();
 
-            let button_a_line = lines.next().unwrap();
-            let button_b_line = lines.next().unwrap();
-            let prize_line = lines.next().unwrap();
-
-            let parse_button = |line: &str| -> anyhow::Result<Point> {
-                let parts: Vec<&str> = line.split(['+', ':', ',']).collect();
-                Ok(Point::new(
-                    parts[2]
-                        .trim()
-                        .parse()
-                        .context(format!("Could not parse button x: {}", parts[2]))?,
-                    parts[4]
-                        .trim()
-                        .parse()
-                        .context(format!("Could not parse button y: {}", parts[4]))?,
-                ))
-            };
-
-            let parse_prize = |line: &str| -> anyhow::Result<Point> {
-                let parts: Vec<&str> = line.split(['=', ',']).collect();
-                Ok(Point::new(
-                    parts[1]
-                        .trim()
-                        .parse()
-                        .context(format!("Could not parse prize x: {}", parts[1]))?,
-                    parts[3]
-                        .trim()
-                        .parse()
-                        .context(format!("Could not parse prize y: {}", parts[3]))?,
-                ))
+            let button_a_line = parse::next(input, chunk, &mut lines, "button A")?;
+            let button_b_line = parse::next(input, chunk, &mut lines, "button B")?;
+            let prize_line = parse::next(input, chunk, &mut lines, "the prize")?;
+
+            // The numbers sit at `x` and `y` among the parts of `line` split at `separators`.
+            let parse_point = |line: &str, separators: &[char], x: usize, y: usize| {
+                let parts: Vec<&str> = line.split(separators).collect();
+                let number = |index: usize, what| match parts.get(index) {
+                    Some(part) => parse::number(input, part.trim()),
+                    None => Err(ParseError::missing(input, line, what)),
+                };
+                Ok::<_, ParseError>(Point::new(number(x, "an x")?, number(y, "a y")?))
             };
+            let parse_button = |line| parse_point(line, &['+', ':', ','], 2, 4);
+            let parse_prize = |line| parse_point(line, &['=', ','], 1, 3);
 
             let button_a = parse_button(button_a_line)?;
             let button_b = parse_button(button_b_line)?;
@@ -74,14 +63,14 @@ impl Solution<Self> for Day13 {
         Ok(entries)
     }
 
-    fn part_a(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
+    fn part_a(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerA> {
         Ok(input
             .iter()
             .filter_map(|entry| solve_press_counts(entry, 0))
             .sum())
     }
 
-    fn part_b(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
+    fn part_b(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerB> {
         Ok(input
             .iter()
             .filter_map(|entry| solve_press_counts(entry, 10_000_000_000_000))
@@ -90,15 +79,11 @@ impl Solution<Self> for Day13 {
 }
 
 fn solve_press_counts(entry: &Entry, offset: isize) -> Option<isize> {
-    let (ax, ay) = (entry.button_a.get().0 as isize, entry.button_a.get().1 as isize);
-    let (bx, by) = (entry.button_b.get().0 as isize, entry.button_b.get().1 as isize);
-    let (px, py) = (entry.prize.get().0 as isize + offset, entry.prize.get().1 as isize + offset);
+    let (button_a, button_b) = (Vector::from(entry.button_a), Vector::from(entry.button_b));
+    let prize = Vector::from(entry.prize) + Vector::new(offset, offset);
+    let ((ax, ay), (bx, by), (px, py)) = (button_a.get(), button_b.get(), prize.get());
 
     let pb = (px * ay - py * ax) / (bx * ay - ax * by);
     let pa = (px - pb * bx) / ax;
-    if pa * ax + pb * bx == px && pa * ay + pb * by == py {
-        Some(pa * 3 + pb)
-    } else {
-        None
-    }
+    (button_a * pa + button_b * pb == prize).then_some(pa * 3 + pb)
 }
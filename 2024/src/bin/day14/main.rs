@@ -1,7 +1,7 @@
-use std::{collections::HashMap, usize};
-
-use anyhow::{Context, Error};
+use anyhow::Error;
 use aoc_2024::*;
+#[cfg(test)]
+use dimensions_2::grid::Grid;
 use dimensions_2::unsigned::{Dimensions, Point};
 use itertools::Itertools;
 
@@ -25,20 +25,18 @@ impl Robot {
     }
 }
 
-fn parse_robot(input: &str) -> anyhow::Result<Robot> {
-    let parts: Vec<&str> = input.split(['=', ',', ' ']).collect();
-    let x = parts[1]
-        .parse()
-        .context(format!("Could not parse position x: {}", parts[1]))?;
-    let y = parts[2]
-        .parse()
-        .context(format!("Could not parse position y: {}", parts[2]))?;
-    let vx = parts[4]
-        .parse()
-        .context(format!("Could not parse velocity x: {}", parts[4]))?;
-    let vy = parts[5]
-        .parse()
-        .context(format!("Could not parse velocity y: {}", parts[5]))?;
+fn parse_robot(input: &str, line: &str) -> ParseResult<Robot> {
+    let parts: Vec<&str> = line.split(['=', ',', ' ']).collect();
+    let part = |index: usize, what| {
+        parts
+            .get(index)
+            .copied()
+            .ok_or_else(|| ParseError::missing(input, line, what))
+    };
+    let x = parse::number(input, part(1, "the position's x")?)?;
+    let y = parse::number(input, part(2, "the position's y")?)?;
+    let vx = parse::number(input, part(4, "the velocity's x")?)?;
+    let vy = parse::number(input, part(5, "the velocity's y")?)?;
     Ok(Robot {
         position: Point::new(x, y),
         velocity: (vx, vy),
@@ -46,49 +44,56 @@ fn parse_robot(input: &str) -> anyhow::Result<Robot> {
 }
 
 impl Solution<Self> for Day14 {
-    type Parsed = Vec<Robot>;
+    type Parsed<'input> = Vec<Robot>;
+
+    type AnswerA = usize;
+
+    type AnswerB = usize;
+
+    type Config = Dimensions;
 
-    type Answer = usize;
+    const CONFIG: Self::Config = Dimensions::new(101, 103);
 
-    const SAMPLE_ANSWER_A: Self::Answer = 12;
+    const SAMPLE_CONFIG: Self::Config = Dimensions::new(11, 7);
 
-    const SAMPLE_ANSWER_B: Self::Answer = 0;
+    const SAMPLE_ANSWER_A: Self::AnswerA = 12;
 
-    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
+    const SAMPLE_ANSWER_B: Self::AnswerB = 1;
+
+    fn parse(input: &str) -> ParseResult<Self::Parsed<'_>> {
         let robots: Vec<Robot> = input
             .lines()
-            .map(|line| parse_robot(line))
+            .map(|line| parse_robot(input, line))
             .collect::<Result<Vec<_>, _>>()?;
         Ok(robots)
     }
 
-    fn part_a(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
-        let dimensions = Dimensions::new(101, 103);
-        let mut robots = input.clone();
-        for _ in 0..100 {
-            robots
-                .iter_mut()
-                .for_each(|robot| robot.move_robot(&dimensions));
-        }
-        let quadrant_counts = count_robots_in_quadrants(&robots, &dimensions);
-        Ok(quadrant_counts.iter().product())
+    fn part_a(input: &Self::Parsed<'_>, config: &Self::Config) -> anyhow::Result<Self::AnswerA> {
+        Ok(safety_factor(input.clone(), *config))
     }
 
-    fn part_b(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
-        let dimensions = Dimensions::new(101, 103);
+    fn part_b(input: &Self::Parsed<'_>, config: &Self::Config) -> anyhow::Result<Self::AnswerB> {
         let mut robots = input.clone();
-        for seconds in 0..101*103 {
-            robots
-                .iter_mut()
-                .for_each(|robot| robot.move_robot(&dimensions));
+        for seconds in 0..config.get_width() * config.get_len() {
             if robots.iter().map(|robot| robot.position).all_unique() {
                 return Ok(seconds);
             }
+            robots.iter_mut().for_each(|robot| robot.move_robot(config));
         }
         Err(Error::msg("No all unique"))
     }
 }
 
+fn safety_factor(mut robots: Vec<Robot>, dimensions: Dimensions) -> usize {
+    for _ in 0..100 {
+        robots
+            .iter_mut()
+            .for_each(|robot| robot.move_robot(&dimensions));
+    }
+    let quadrant_counts = count_robots_in_quadrants(&robots, &dimensions);
+    quadrant_counts.iter().product()
+}
+
 fn count_robots_in_quadrants(robots: &[Robot], dimensions: &Dimensions) -> [usize; 4] {
     let mid_x = dimensions.get_width() / 2;
     let mid_y = dimensions.get_len() / 2;
@@ -111,42 +116,58 @@ fn count_robots_in_quadrants(robots: &[Robot], dimensions: &Dimensions) -> [usiz
     quadrant_counts
 }
 
-fn print_quadrants(robots: &[Robot], dimensions: &Dimensions) {
-    let (width, len) = dimensions.get();
-    let position_robot_map: HashMap<Point, Vec<&Robot>> =
-        robots.iter().into_group_map_by(|robot| robot.position);
-    for y in 0..len {
-        if y == len / 2 {
-            println!();
-            continue;
-        }
-        for x in 0..width {
-            if x ==  width / 2 {
-                print!(" ");
-                continue;
-            }
-            if position_robot_map.contains_key(&Point::new(x, y)) {
-                print!("#");
-                continue;
-            }
-            print!(".");
-        }
-        println!();
-    }
+/// The number of robots on each tile as the puzzle draws them, in base 36 and `+` from 36 robots on.
+/// Highlight the middle row and column when rendering to see the quadrants.
+#[cfg(test)]
+fn robot_map(robots: &[Robot], dimensions: Dimensions) -> Grid<Option<char>> {
+    let mut counts = Grid::new(dimensions, 0);
+    robots.iter().for_each(|robot| counts[robot.position] += 1);
+    counts.map(|&count| (count > 0).then(|| char::from_digit(count, 36).unwrap_or('+')))
 }
 
 #[cfg(test)]
-mod test {
-    use aoc_2024::dimensions_2::unsigned::{Dimensions, Point};
+mod tests {
+    use aoc_2024::{
+        dimensions_2::unsigned::{Dimensions, Point},
+        Solution,
+    };
 
-    use crate::Robot;
+    use crate::{robot_map, Day14, Robot};
 
     #[test]
     fn test_move_robot_wrap() {
         let dimensions = Dimensions::new(11, 7);
-        let mut robot = Robot { position: Point::new(8, 2), velocity: (2, -3) };
+        let mut robot = Robot {
+            position: Point::new(8, 2),
+            velocity: (2, -3),
+        };
         let expected = Point::new(10, 6);
         robot.move_robot(&dimensions);
         assert_eq!(expected, robot.position);
     }
+
+    #[test]
+    fn draws_robots_after_100_seconds() {
+        let dimensions = Day14::SAMPLE_CONFIG;
+        let mut robots = Day14::parse(include_str!("sample.txt")).unwrap();
+        for _ in 0..100 {
+            robots
+                .iter_mut()
+                .for_each(|robot| robot.move_robot(&dimensions));
+        }
+        assert_eq!(
+            robot_map(&robots, dimensions).render().to_string(),
+            "......2..1.\n...........\n1..........\n.11........\n.....1.....\n...12......\n.1....1....\n"
+        );
+    }
+
+    #[test]
+    fn draws_crowded_tiles() {
+        let robot = Robot {
+            position: Point::new(1, 0),
+            velocity: (0, 0),
+        };
+        let map = robot_map(&[robot; 40], Dimensions::new(2, 1));
+        assert_eq!(map.render().to_string(), ".+\n");
+    }
 }
@@ -1,12 +1,17 @@
 use core::panic;
 
 use aoc_2024::*;
-use dimensions_2::{unsigned::Point, Direction};
-use itertools::Itertools;
+use dimensions_2::{
+    grid::{Grid, Tile},
+    render::{Colour, Render},
+    unsigned::{Dimensions, Point},
+    Direction,
+};
+use parse::Reason;
 
 aoc!(Day15);
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 enum MapObject {
     Air,
     Box,
@@ -16,7 +21,18 @@ enum MapObject {
 
 use MapObject::*;
 
-#[derive(Clone, Copy, Debug)]
+impl Render for MapObject {
+    fn render(&self) -> char {
+        match self {
+            Air => '.',
+            Box => 'O',
+            Robot => '@',
+            Wall => '#',
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 enum MapObject2 {
     Base(MapObject),
     BoxLeft,
@@ -25,129 +41,129 @@ enum MapObject2 {
 
 use MapObject2::*;
 
+impl Render for MapObject2 {
+    fn render(&self) -> char {
+        match self {
+            Base(obj) => obj.render(),
+            BoxLeft => '[',
+            BoxRight => ']',
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 struct State {
-    map: Vec<Vec<MapObject>>,
+    map: Grid<MapObject>,
     robot: Point,
     moves: Vec<Direction>,
 }
 
 impl State {
+    /// The map with the robot we're tracking in red, to show in the panics.
+    fn rendered(&self) -> String {
+        self.map
+            .render()
+            .highlight([self.robot], Colour::Red)
+            .to_string()
+    }
+
     fn update(&mut self, direction: &Direction) {
-        let (nx, ny) = (self.robot + direction.delta()).get();
-        let map_object = self.map[ny][nx];
+        let new_point = self.robot + *direction;
+        let map_object = self.map[new_point];
         match map_object {
             Air => {
-                let (x, y) = self.robot.get();
-                self.map[y][x] = Air;
-                self.map[ny][nx] = Robot;
-                self.robot = Point::new(nx, ny);
+                self.map[self.robot] = Air;
+                self.map[new_point] = Robot;
+                self.robot = new_point;
             }
             Box => {
-                let (mut cur_x, mut cur_y) = (nx, ny);
-                while let Box = self.map[cur_y][cur_x] {
-                    cur_x = cur_x.wrapping_add_signed(direction.delta().0);
-                    cur_y = cur_y.wrapping_add_signed(direction.delta().1);
+                let mut cur_point = new_point;
+                while let Box = self.map[cur_point] {
+                    cur_point += direction.delta();
                 }
-                let not_box = self.map[cur_y][cur_x];
+                let not_box = self.map[cur_point];
                 match not_box {
                     Air => {
-                        let (x, y) = self.robot.get();
-                        self.map[cur_y][cur_x] = Box;
-                        self.map[y][x] = Air;
-                        self.map[ny][nx] = Robot;
-                        self.robot = Point::new(nx, ny);
+                        self.map[cur_point] = Box;
+                        self.map[self.robot] = Air;
+                        self.map[new_point] = Robot;
+                        self.robot = new_point;
                     }
                     Box => unreachable!(),
-                    Robot => panic!("There's a second robot!"),
+                    Robot => panic!("There's a second robot!\n{}", self.rendered()),
                     Wall => (),
                 }
             }
             Wall => (),
-            Robot => panic!("New position is the robot."),
-        }
-    }
-
-    fn print_state(&self) {
-        let map = self.map.clone();
-        for row in map {
-            for obj in row {
-                match obj {
-                    Air => print!("."),
-                    Box => print!("O"),
-                    Robot => print!("@"),
-                    Wall => print!("#"),
-                }
-            }
-            println!();
+            Robot => panic!("New position is the robot.\n{}", self.rendered()),
         }
     }
 }
 
 #[derive(Clone, Debug)]
 struct State2 {
-    map: Vec<Vec<MapObject2>>,
+    map: Grid<MapObject2>,
     robot: Point,
-    moves: Vec<Direction>,
 }
 
 impl State2 {
+    /// The map with the robot we're tracking in red, to show in the panics.
+    fn rendered(&self) -> String {
+        self.map
+            .render()
+            .highlight([self.robot], Colour::Red)
+            .to_string()
+    }
+
     fn from_part_a(state: &State) -> Self {
-        let map = state
-            .map
-            .iter()
-            .map(|row| {
-                row.iter()
-                    .flat_map(|obj| match obj {
-                        Air | Wall => vec![Base(*obj), Base(*obj)],
-                        Box => vec![BoxLeft, BoxRight],
-                        Robot => vec![Base(Robot), Base(Air)],
-                    })
-                    .collect_vec()
-            })
-            .collect_vec();
+        let (width, len) = state.map.dimensions().get();
+        let map = Grid::from_fn(Dimensions::new(width * 2, len), |point| {
+            let (x, y) = point.get();
+            match (state.map[Point::new(x / 2, y)], x % 2) {
+                (Box, 0) => BoxLeft,
+                (Box, _) => BoxRight,
+                (Robot, 1) => Base(Air),
+                (obj, _) => Base(obj),
+            }
+        });
 
         Self {
             map,
             robot: Point::new(state.robot.get().0 * 2, state.robot.get().1),
-            moves: state.moves.clone(),
         }
     }
 
     fn update(&mut self, direction: &Direction) {
-        let (nx, ny) = (self.robot + direction.delta()).get();
-        let map_object = self.map[ny][nx];
+        let new_point = self.robot + *direction;
+        let map_object = self.map[new_point];
         match map_object {
             Base(Air) => {
-                let (x, y) = self.robot.get();
-                self.map[y][x] = Base(Air);
-                self.map[ny][nx] = Base(Robot);
-                self.robot = Point::new(nx, ny);
+                self.map[self.robot] = Base(Air);
+                self.map[new_point] = Base(Robot);
+                self.robot = new_point;
             }
             BoxLeft | BoxRight => {
                 let try_push = self.try_push(self.robot, direction);
                 if try_push {
                     self.push(self.robot, *direction);
-                    let (x, y) = self.robot.get();
-                    self.map[y][x] = Base(Air);
-                    self.map[ny][nx] = Base(Robot);
-                    self.robot = Point::new(nx, ny);
+                    self.map[self.robot] = Base(Air);
+                    self.map[new_point] = Base(Robot);
+                    self.robot = new_point;
                 }
             }
             Base(Wall) => (),
-            Base(Robot) => panic!("New position is the robot."),
+            Base(Robot) => panic!("New position is the robot.\n{}", self.rendered()),
             Base(Box) => unreachable!("Box in v2 state."),
         }
     }
 
     fn try_push(&self, start_pos: Point, direction: &Direction) -> bool {
         let new_point = start_pos + direction.delta();
-        let (nx, ny) = new_point.get();
-        let map_obj = self.map[ny][nx];
+        let map_obj = self.map[new_point];
         match (map_obj, direction) {
             (Base(Air), _) => true,
             (Base(Wall), _) => false,
-            (Base(Robot), _) => panic!("Second robot"),
+            (Base(Robot), _) => panic!("Second robot\n{}", self.rendered()),
             (Base(Box), _) => unreachable!(),
             (obj, Direction::Right) => match obj {
                 BoxLeft | BoxRight => self.try_push(new_point, direction),
@@ -172,184 +188,131 @@ impl State2 {
     }
 
     fn push(&mut self, start_pos: Point, direction: Direction) {
-        let (sx, sy) = start_pos.get();
         let new_point = start_pos + direction.delta();
-        let (nx, ny) = new_point.get();
 
-        let map_obj = self.map[ny][nx];
+        let map_obj = self.map[new_point];
 
         match (map_obj, direction) {
-            (Base(Air), _) => {
-                let temp = self.map[ny][nx];
-                self.map[ny][nx] = self.map[sy][sx];
-                self.map[sy][sx] = temp;
-            }
+            (Base(Air), _) => self.map.swap(new_point, start_pos),
             (Base(Wall), _) => (),
             (Base(_), _) => unreachable!(),
             (obj, Direction::Right) => match obj {
                 BoxLeft | BoxRight => {
                     self.push(new_point, direction);
-                    let temp = self.map[ny][nx];
-                    self.map[ny][nx] = self.map[sy][sx];
-                    self.map[sy][sx] = temp;
+                    self.map.swap(new_point, start_pos);
                 }
                 _ => unreachable!(),
             },
             (obj, Direction::Left) => match obj {
                 BoxLeft | BoxRight => {
                     self.push(new_point, direction);
-                    let temp = self.map[ny][nx];
-                    self.map[ny][nx] = self.map[sy][sx];
-                    self.map[sy][sx] = temp;
+                    self.map.swap(new_point, start_pos);
                 }
                 _ => unreachable!(),
             },
             (obj, Direction::Up) | (obj, Direction::Down) => match obj {
                 BoxLeft => {
+                    let other_half = new_point + (1, 0);
                     self.push(new_point, direction);
-                    self.push(new_point + (1, 0), direction);
-                    let temp1 = self.map[ny][nx + 1];
-                    let temp2 = self.map[ny][nx];
-                    let temp3 = self.map[sy][sx];
-
-                    self.map[ny][nx + 1] = temp2;
-                    self.map[ny][nx] = temp3;
-                    self.map[sy][sx] = temp1;
+                    self.push(other_half, direction);
+                    let temp1 = self.map[other_half];
+                    let temp2 = self.map[new_point];
+                    let temp3 = self.map[start_pos];
+
+                    self.map[other_half] = temp2;
+                    self.map[new_point] = temp3;
+                    self.map[start_pos] = temp1;
                 }
                 BoxRight => {
+                    let other_half = new_point + (-1, 0);
                     self.push(new_point, direction);
-                    self.push(new_point + (-1, 0), direction);
-                    let temp1 = self.map[ny][nx - 1];
-                    let temp2 = self.map[ny][nx];
-                    let temp3 = self.map[sy][sx];
-
-                    self.map[ny][nx - 1] = temp2;
-                    self.map[ny][nx] = temp3;
-                    self.map[sy][sx] = temp1;
+                    self.push(other_half, direction);
+                    let temp1 = self.map[other_half];
+                    let temp2 = self.map[new_point];
+                    let temp3 = self.map[start_pos];
+
+                    self.map[other_half] = temp2;
+                    self.map[new_point] = temp3;
+                    self.map[start_pos] = temp1;
                 }
                 _ => unreachable!(),
             },
         }
     }
+}
 
-    fn print_state(&self) {
-        let map = self.map.clone();
-        for row in map {
-            for obj in row {
-                match obj {
-                    Base(Air) => print!("."),
-                    BoxLeft => print!("["),
-                    BoxRight => print!("]"),
-                    Base(Robot) => print!("@"),
-                    Base(Wall) => print!("#"),
-                    Base(Box) => unreachable!("Boxes in state 2"),
-                }
-            }
-            println!();
-        }
-    }
+/// The GPS coordinate the puzzle sums.
+fn gps(point: Point) -> usize {
+    let (x, y) = point.get();
+    x + 100 * y
 }
 
 impl Solution<Self> for Day15 {
-    type Parsed = State;
-
-    type Answer = usize;
-
-    const SAMPLE_ANSWER_A: Self::Answer = 10092;
-
-    const SAMPLE_ANSWER_B: Self::Answer = 9021;
-
-    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
-        let mut robot = Point::new(0, 0);
-        let map = input
-            .lines()
-            .enumerate()
-            .take_while(|(_, line)| *line != "")
-            .map(|(y, line)| {
-                line.chars()
-                    .enumerate()
-                    .map(|(x, char)| match char {
-                        '.' => Air,
-                        '@' => {
-                            robot = Point::new(x, y);
-                            Robot
-                        }
-                        'O' => Box,
-                        '#' => Wall,
-                        _ => panic!("Unknown object to parse"),
-                    })
-                    .collect_vec()
+    type Parsed<'input> = State;
+
+    type AnswerA = usize;
+
+    type AnswerB = usize;
+
+    type Config = ();
+
+    const CONFIG: Self::Config = ();
+
+    const SAMPLE_CONFIG: Self::Config = ();
+
+    const SAMPLE_ANSWER_A: Self::AnswerA = 10092;
+
+    const SAMPLE_ANSWER_B: Self::AnswerB = 9021;
+
+    const EXAMPLES: &'static [Example<Self::AnswerA, Self::AnswerB>] = &[Example {
+        name: "simple",
+        input: include_str!("sample.txt.simple"),
+        answer_a: Some(2028),
+        answer_b: None,
+    }];
+
+    fn parse(input: &str) -> ParseResult<Self::Parsed<'_>> {
+        let (map_text, moves_text) = input.split_once("\n\n").unwrap_or((input, ""));
+        let (map, markers) = Grid::parse_with(map_text, |ch, _| match ch {
+            '.' => Some(Tile::Plain(Air)),
+            '@' => Some(Tile::Marker(Robot)),
+            'O' => Some(Tile::Plain(Box)),
+            '#' => Some(Tile::Plain(Wall)),
+            _ => None,
+        })?;
+        let robot = markers.single(map_text, '@', "a robot")?;
+        let moves = parse::chars(moves_text)
+            .flat_map(|(_, line)| line)
+            .map(|(_, char, text)| match char {
+                '>' => Ok(Direction::Right),
+                'v' => Ok(Direction::Down),
+                '<' => Ok(Direction::Left),
+                '^' => Ok(Direction::Up),
+                _ => Err(ParseError::at(input, text, Reason::UnknownChar(char))),
             })
-            .collect_vec();
-        let moves = input
-            .lines()
-            .skip_while(|line| *line != "")
-            .skip(1)
-            .flat_map(|line| {
-                line.chars()
-                    .map(|char| match char {
-                        '>' => Direction::Right,
-                        'v' => Direction::Down,
-                        '<' => Direction::Left,
-                        '^' => Direction::Up,
-                        _ => panic!("Unknown direction to parse"),
-                    })
-                    .collect_vec()
-                    .into_iter()
-            })
-            .collect_vec();
+            .collect::<ParseResult<_>>()?;
         Ok(State { map, robot, moves })
     }
 
-    fn part_a(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
+    fn part_a(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerA> {
         let mut state = input.clone();
 
-        input.moves.iter().enumerate().for_each(|(_i, direction)| state.update(direction));
-
-        Ok(state
-            .map
+        input
+            .moves
             .iter()
-            .enumerate()
-            .flat_map(|(y, row)| {
-                row.iter()
-                    .enumerate()
-                    .filter_map(|(x, obj)| match obj {
-                        Air => None,
-                        Box => Some(x + 100 * y),
-                        Robot => None,
-                        Wall => None,
-                    })
-                    .collect_vec()
-                    .into_iter()
-            })
-            .sum())
+            .for_each(|direction| state.update(direction));
+
+        Ok(state.map.positions_of(&Box).map(gps).sum())
     }
 
-    fn part_b(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
-        let mut state = State2::from_part_a(&input);
+    fn part_b(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerB> {
+        let mut state = State2::from_part_a(input);
 
         input
             .moves
             .iter()
-            .enumerate()
-            .for_each(|(_i, direction)| state.update(direction));
-
+            .for_each(|direction| state.update(direction));
 
-        Ok(state
-            .map
-            .iter()
-            .enumerate()
-            .flat_map(|(y, row)| {
-                row.iter()
-                    .enumerate()
-                    .filter_map(|(x, obj)| match obj {
-                        Base(_) => None,
-                        BoxLeft => Some(x + 100 * y),
-                        _ => None,
-                    })
-                    .collect_vec()
-                    .into_iter()
-            })
-            .sum())
+        Ok(state.map.positions_of(&BoxLeft).map(gps).sum())
     }
 }
@@ -1,10 +1,14 @@
 use std::{
+    cmp::Ordering,
     collections::{HashMap, HashSet, VecDeque},
-    usize,
 };
 
 use aoc_2024::*;
-use dimensions_2::{unsigned::Point, Direction};
+use dimensions_2::{
+    grid::{Grid, Tile},
+    unsigned::Point,
+    Direction,
+};
 use itertools::Itertools;
 
 aoc!(Day16);
@@ -13,100 +17,138 @@ aoc!(Day16);
 struct Maze {
     start: Point,
     end: Point,
-    maze: Vec<Vec<char>>,
+    maze: Grid<char>,
 }
 
 impl Solution<Self> for Day16 {
-    type Parsed = Maze;
-
-    type Answer = usize;
-
-    const SAMPLE_ANSWER_A: Self::Answer = 7036;
-
-    const SAMPLE_ANSWER_B: Self::Answer = 45;
-
-    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
-        let mut start = Point::default();
-        let mut end = Point::default();
-        let maze = input
-            .lines()
-            .enumerate()
-            .map(|(y, line)| {
-                line.chars()
-                    .enumerate()
-                    .map(|(x, char)| match char {
-                        'S' => {
-                            start = Point::new(x, y);
-                            'S'
-                        }
-                        'E' => {
-                            end = Point::new(x, y);
-                            'E'
-                        }
-                        c => c,
-                    })
-                    .collect_vec()
-            })
-            .collect_vec();
+    type Parsed<'input> = Maze;
+
+    type AnswerA = usize;
+
+    type AnswerB = usize;
+
+    type Config = ();
+
+    const CONFIG: Self::Config = ();
+
+    const SAMPLE_CONFIG: Self::Config = ();
+
+    const SAMPLE_ANSWER_A: Self::AnswerA = 7036;
+
+    const SAMPLE_ANSWER_B: Self::AnswerB = 45;
+
+    const EXAMPLES: &'static [Example<Self::AnswerA, Self::AnswerB>] = &[
+        Example {
+            name: "second",
+            input: include_str!("sample.txt.second"),
+            answer_a: Some(11048),
+            answer_b: Some(64),
+        },
+        Example {
+            name: "bad",
+            input: include_str!("sample.txt.bad"),
+            answer_a: Some(4013),
+            answer_b: Some(14),
+        },
+    ];
+
+    fn parse(input: &str) -> ParseResult<Self::Parsed<'_>> {
+        let (maze, markers) = Grid::parse_with(input, |ch, _| match ch {
+            '#' | '.' => Some(Tile::Plain(ch)),
+            'S' | 'E' => Some(Tile::Marker(ch)),
+            _ => None,
+        })?;
+        let start = markers.single(input, 'S', "a start")?;
+        let end = markers.single(input, 'E', "an end")?;
         Ok(Maze { start, end, maze })
     }
 
-    fn part_a(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
-        let (distances, _prev) = djikstras((input.start, Direction::Right), &input.maze, input.end);
-        let (x, y) = input.end.get();
-        Ok(distances[y][x])
+    fn part_a(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerA> {
+        let (distances, _prev, _end_directions) =
+            djikstras((input.start, Direction::Right), &input.maze, input.end);
+        Ok(distances[input.end])
     }
 
-    fn part_b(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
-        let (_distances, prev) = djikstras((input.start, Direction::Right), &input.maze, input.end);
-
-        let mut paths = HashSet::new();
-        let mut queue = vec![(input.end, Direction::Up)];
-        paths.insert(input.end);
-        while let Some(cur) = queue.pop() {
-            if let Some(prev_set) = prev.get(&cur) {
-                for &(point, direction) in prev_set {
-                    paths.insert(point);
-                    queue.push((point, direction));
-                }
+    fn part_b(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerB> {
+        let (_distances, prev, end_directions) =
+            djikstras((input.start, Direction::Right), &input.maze, input.end);
+        Ok(count_best_seats(input.end, &prev, end_directions))
+    }
+
+    fn solve_both(
+        input: &Self::Parsed<'_>,
+        _: &Self::Config,
+    ) -> Option<anyhow::Result<(Self::AnswerA, Self::AnswerB)>> {
+        let (distances, prev, end_directions) =
+            djikstras((input.start, Direction::Right), &input.maze, input.end);
+        Some(Ok((
+            distances[input.end],
+            count_best_seats(input.end, &prev, end_directions),
+        )))
+    }
+}
+
+fn count_best_seats(end: Point, prev: &Predecessors, end_directions: Vec<Direction>) -> usize {
+    let mut paths = HashSet::new();
+    let mut queue = end_directions
+        .into_iter()
+        .map(|direction| (end, direction))
+        .collect_vec();
+    paths.insert(end);
+    while let Some(cur) = queue.pop() {
+        if let Some(prev_set) = prev.get(&cur) {
+            for &(point, direction) in prev_set {
+                paths.insert(point);
+                queue.push((point, direction));
             }
         }
-        // print_path(&input.maze, &paths);
-        Ok(paths.len())
     }
+    paths.len()
 }
 
+type Predecessors = HashMap<(Point, Direction), HashSet<(Point, Direction)>>;
+
 fn djikstras(
     start: (Point, Direction),
-    maze: &Vec<Vec<char>>,
+    maze: &Grid<char>,
     end: Point,
-) -> (
-    Vec<Vec<usize>>,
-    HashMap<(Point, Direction), HashSet<(Point, Direction)>>,
-) {
-    let mut dist: Vec<Vec<usize>> = vec![vec![usize::MAX; maze[0].len()]; maze.len()];
-    let mut prev: HashMap<(Point, Direction), HashSet<(Point, Direction)>> = HashMap::new();
+) -> (Grid<usize>, Predecessors, Vec<Direction>) {
+    let mut dist = Grid::new(maze.dimensions(), usize::MAX);
+    let mut prev: Predecessors = HashMap::new();
+    // The end can be reached facing more than one way, backtracking has to start from each
+    // direction that arrives at the lowest score.
+    let mut end_directions = Vec::new();
     let mut queue: VecDeque<(Point, Direction, usize)> = VecDeque::new();
     queue.push_back((start.0, start.1, 0));
-    dist[start.0.get().1][start.0.get().0] = 0;
+    dist[start.0] = 0;
 
     while let Some(cur) = queue.pop_front() {
         let neighbors = [cur.1.rotate_right(), cur.1.rotate_left(), cur.1];
 
         for dir in neighbors {
             let new_point = cur.0 + dir.delta();
-            let (nx, ny) = new_point.get();
-            if maze[ny][nx] != '#' {
+            if maze.get(new_point).is_some_and(|&tile| tile != '#') {
                 let new_score = match dir {
                     _ if dir == cur.1 => cur.2 + 1,
                     _ => cur.2 + 1001,
                 };
 
-                if dist[end.get().1][end.get().0] < new_score && maze[ny][nx] != 'E' {
+                if dist[end] < new_score && maze[new_point] != 'E' {
                     continue;
                 }
 
-                if new_score < dist[ny][nx] {
+                if new_point == end {
+                    match new_score.cmp(&dist[new_point]) {
+                        Ordering::Less => end_directions = vec![dir],
+                        Ordering::Equal if !end_directions.contains(&dir) => {
+                            end_directions.push(dir);
+                            prev.entry((new_point, dir)).or_default();
+                        }
+                        _ => (),
+                    }
+                }
+
+                if new_score < dist[new_point] {
                     prev.entry((new_point, dir))
                         .and_modify(|set| {
                             set.clear();
@@ -123,10 +165,10 @@ fn djikstras(
                     });
                 }
 
-                if dist[ny][nx] >= new_score {
-                    dist[ny][nx] = new_score;
+                if dist[new_point] >= new_score {
+                    dist[new_point] = new_score;
 
-                    if maze[ny][nx] == 'E' {
+                    if maze[new_point] == 'E' {
                         continue;
                     }
 
@@ -139,18 +181,5 @@ fn djikstras(
         }
     }
 
-    (dist, prev)
-}
-
-fn print_path(maze: &Vec<Vec<char>>, paths: &HashSet<Point>) {
-    for (y, row) in maze.iter().enumerate() {
-        for (x, map_char) in row.iter().enumerate() {
-            if paths.contains(&Point::new(x, y)) {
-                print!("O");
-            } else {
-                print!("{}", map_char);
-            }
-        }
-        println!();
-    }
+    (dist, prev, end_directions)
 }
@@ -1,40 +1,47 @@
 use aoc_2024::*;
-use itertools::Itertools;
 
 aoc!(Day2);
 
 impl Solution<Self> for Day2 {
-    type Parsed = Vec<Vec<u64>>;
+    type Parsed<'input> = Vec<Vec<u64>>;
 
-    type Answer = u64;
+    type AnswerA = u64;
 
-    const SAMPLE_ANSWER_A: Self::Answer = 2;
+    type AnswerB = u64;
 
-    const SAMPLE_ANSWER_B: Self::Answer = 4;
+    type Config = ();
 
-    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
-        Ok(input
+    const CONFIG: Self::Config = ();
+
+    const SAMPLE_CONFIG: Self::Config = ();
+
+    const SAMPLE_ANSWER_A: Self::AnswerA = 2;
+
+    const SAMPLE_ANSWER_B: Self::AnswerB = 4;
+
+    fn parse(input: &str) -> ParseResult<Self::Parsed<'_>> {
+        input
             .lines()
             .map(|line| {
                 line.split_whitespace()
-                    .map(|num| num.parse::<u64>().expect("Could not parse number"))
-                    .collect_vec()
+                    .map(|num| parse::number(input, num))
+                    .collect()
             })
-            .collect_vec())
+            .collect()
     }
 
-    fn part_a(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
+    fn part_a(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerA> {
         Ok(input.iter().filter(|report| is_safe(report)).count() as u64)
     }
 
-    fn part_b(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
+    fn part_b(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerB> {
         Ok(input
             .iter()
             .filter(|report| {
                 let mut increasing: Option<bool> = None;
                 let to_remove = report.windows(2).position(|window| {
                     let diff = window[1].abs_diff(window[0]);
-                    if increasing == None {
+                    if increasing.is_none() {
                         increasing = Some(window[0] < window[1]);
                     }
                     let allowed_change = match increasing {
@@ -63,7 +70,7 @@ fn is_safe(report: &[u64]) -> bool {
     let mut increasing: Option<bool> = None;
     report.windows(2).all(|window| {
         let diff = window[1].abs_diff(window[0]);
-        if increasing == None {
+        if increasing.is_none() {
             increasing = Some(window[0] < window[1]);
         }
         let allowed_change = match increasing {
@@ -1,8 +1,14 @@
+use std::sync::LazyLock;
+
 use aoc_2024::*;
 use regex::Regex;
 
 aoc!(Day3);
 
+static INSTRUCTION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(do\(\)|don't\(\)|mul\((\d+),(\d+)\))").expect("The pattern is valid")
+});
+
 #[derive(Debug, Clone)]
 struct Mul {
     is_do: bool,
@@ -17,41 +23,39 @@ impl Mul {
 }
 
 impl Solution<Self> for Day3 {
-    type Parsed = Vec<Mul>;
+    type Parsed<'input> = Vec<Mul>;
+
+    type AnswerA = u64;
+
+    type AnswerB = u64;
+
+    type Config = ();
+
+    const CONFIG: Self::Config = ();
 
-    type Answer = u64;
+    const SAMPLE_CONFIG: Self::Config = ();
 
-    const SAMPLE_ANSWER_A: Self::Answer = 161;
+    const SAMPLE_ANSWER_A: Self::AnswerA = 161;
 
-    const SAMPLE_ANSWER_B: Self::Answer = 48;
+    const SAMPLE_ANSWER_B: Self::AnswerB = 48;
 
-    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
-        let re = Regex::new(r"(do\(\)|don't\(\)|mul\((\d+),(\d+)\))")?;
+    fn parse(input: &str) -> ParseResult<Self::Parsed<'_>> {
         let mut is_do = true;
         let mut muls = Vec::new();
-        let captures = re.captures_iter(input);
+        let captures = INSTRUCTION.captures_iter(input);
         for capture in captures {
             if let Some(matched) = capture.get(0) {
                 match matched.as_str() {
                     "do()" => is_do = true,
                     "don't()" => is_do = false,
                     _ => {
-                        let mul = Mul {
-                            is_do,
-                            a: capture
-                                .get(2)
-                                .map(|str| {
-                                    str.as_str().parse::<u64>().expect("Could not parse int")
-                                })
-                                .expect("Could not parse"),
-                            b: capture
-                                .get(3)
-                                .map(|str| {
-                                    str.as_str().parse::<u64>().expect("Could not parse int")
-                                })
-                                .expect("Could not parse"),
-                        };
-                        muls.push(mul);
+                        if let (Some(a), Some(b)) = (capture.get(2), capture.get(3)) {
+                            muls.push(Mul {
+                                is_do,
+                                a: parse::number(input, a.as_str())?,
+                                b: parse::number(input, b.as_str())?,
+                            });
+                        }
                     }
                 }
             }
@@ -59,11 +63,11 @@ impl Solution<Self> for Day3 {
         Ok(muls)
     }
 
-    fn part_a(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
+    fn part_a(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerA> {
         Ok(input.iter().map(Mul::product).sum())
     }
 
-    fn part_b(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
+    fn part_b(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerB> {
         Ok(input.iter().filter(|mul| mul.is_do).map(Mul::product).sum())
     }
 }
@@ -1,102 +1,77 @@
 use aoc_2024::*;
-use dimensions_2::{extended::Diagonals, unsigned::{Dimensions, Point}};
-use itertools::Itertools;
+use dimensions_2::{extended::Diagonals, grid::Grid};
 
-const XMAS: &str = "XMAS";
+const XMAS: &[u8] = b"XMAS";
 
 aoc!(Day4);
 
 impl Solution<Self> for Day4 {
-    type Parsed = Vec<Vec<char>>;
+    type Parsed<'input> = Grid<u8, &'input [u8]>;
 
-    type Answer = usize;
+    type AnswerA = usize;
 
-    const SAMPLE_ANSWER_A: Self::Answer = 18;
+    type AnswerB = usize;
 
-    const SAMPLE_ANSWER_B: Self::Answer = 9;
+    type Config = ();
 
-    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
-        Ok(input.lines().map(|line| line.chars().collect()).collect())
+    const CONFIG: Self::Config = ();
+
+    const SAMPLE_CONFIG: Self::Config = ();
+
+    const SAMPLE_ANSWER_A: Self::AnswerA = 18;
+
+    const SAMPLE_ANSWER_B: Self::AnswerB = 9;
+
+    const EXAMPLES: &'static [Example<Self::AnswerA, Self::AnswerB>] = &[Example {
+        name: "debug",
+        input: include_str!("debug_sample.txt"),
+        answer_a: Some(6),
+        answer_b: Some(0),
+    }];
+
+    fn parse(input: &str) -> ParseResult<Self::Parsed<'_>> {
+        Ok(Grid::from_lines(input)?)
     }
 
-    fn part_a(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
-        let dimension = Dimensions::new(input[0].len(), input.len());
-        let mut candidates: Vec<Point> = Vec::new();
-        for (y, row) in input.iter().enumerate() {
-            for (x, &character) in row.iter().enumerate() {
-                if character == 'X' {
-                    candidates.push(Point::new(x, y));
-                }
-            }
-        }
-        Ok(candidates
-            .iter()
+    fn part_a(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerA> {
+        Ok(input
+            .positions_of(&b'X')
             .map(|start_pos| {
-                let remaining_directions = Diagonals::iter()
+                Diagonals::iter()
                     .filter(|direction| {
-                        let final_point = *start_pos + (direction.delta().0 * 3, direction.delta().1 * 3);
-                        dimension.is_within_bounds_exclusive(final_point)
+                        let mut cur_pos = start_pos;
+                        XMAS.iter().all(|letter| {
+                            let matches = input.get(cur_pos) == Some(letter);
+                            cur_pos += direction.delta();
+                            matches
+                        })
                     })
-                    .collect_vec();
-                (start_pos, remaining_directions)
-            })
-            .map(|(pos, remaining_directions)| {
-                let mut words = 0;
-                remaining_directions.iter().for_each(|direction| {
-                    let mut cur_pos = pos.clone();
-                    let mut word = "".to_string();
-                    for _ in 0..4 {
-                        let (x, y) = cur_pos.get();
-                        word.push(input[y][x]);
-                        cur_pos += direction.delta();
-                    }
-                    if word == XMAS {
-                        words += 1;
-                    }
-                });
-                words
+                    .count()
             })
             .sum())
     }
 
-    fn part_b(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
-        let dimension = Dimensions::new(input[0].len(), input.len());
-        let mut candidates: Vec<Point> = Vec::new();
-        for (y, row) in input.iter().enumerate() {
-            for (x, &character) in row.iter().enumerate() {
-                if character == 'A' {
-                    candidates.push(Point::new(x, y));
-                }
-            }
-        }
-
-        Ok(candidates
-            .iter()
-            .filter(|pos| {
-                let bottom_left = **pos + Diagonals::DownLeft.delta();
-                let bottom_right = **pos + Diagonals::DownRight.delta();
-                let top_right = **pos + Diagonals::UpRight.delta();
-                let top_left = **pos + Diagonals::UpLeft.delta();
-
-                if !dimension.is_within_bounds_exclusive(bottom_left)
-                    || !dimension.is_within_bounds_exclusive(bottom_right)
-                    || !dimension.is_within_bounds_exclusive(top_left)
-                    || !dimension.is_within_bounds_exclusive(top_right) {
-                        return false;
-                }
-
-                let bottom_left = input[bottom_left.get().1][bottom_left.get().0];
-                let bottom_right = input[bottom_right.get().1][bottom_right.get().0];
-                let top_left = input[top_left.get().1][top_left.get().0];
-                let top_right = input[top_right.get().1][top_right.get().0];
+    fn part_b(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerB> {
+        Ok(input
+            .positions_of(&b'A')
+            .filter(|&pos| {
+                let corner = |diagonal: Diagonals| input.get(pos + diagonal.delta()).copied();
+                let (Some(bottom_left), Some(bottom_right), Some(top_left), Some(top_right)) = (
+                    corner(Diagonals::DownLeft),
+                    corner(Diagonals::DownRight),
+                    corner(Diagonals::UpLeft),
+                    corner(Diagonals::UpRight),
+                ) else {
+                    return false;
+                };
 
-                match ((bottom_left, top_right), (bottom_right, top_left)) {
-                    (('M', 'S'), ('M', 'S')) => true,
-                    (('S', 'M'), ('M', 'S')) => true,
-                    (('S', 'M'), ('S', 'M')) => true,
-                    (('M', 'S'), ('S', 'M')) => true,
-                    _ => false,
-                }
+                matches!(
+                    ((bottom_left, top_right), (bottom_right, top_left)),
+                    ((b'M', b'S'), (b'M', b'S'))
+                        | ((b'S', b'M'), (b'M', b'S'))
+                        | ((b'S', b'M'), (b'S', b'M'))
+                        | ((b'M', b'S'), (b'S', b'M'))
+                )
             })
             .count())
     }
@@ -6,55 +6,63 @@ use itertools::Itertools;
 aoc!(Day5);
 
 impl Solution<Self> for Day5 {
-    type Parsed = (Vec<(u64, u64)>, Vec<Vec<u64>>);
+    type Parsed<'input> = (Vec<(u64, u64)>, Vec<Vec<u64>>);
 
-    type Answer = u64;
+    type AnswerA = u64;
 
-    const SAMPLE_ANSWER_A: Self::Answer = 143;
+    type AnswerB = u64;
 
-    const SAMPLE_ANSWER_B: Self::Answer = 123;
+    type Config = ();
 
-    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
+    const CONFIG: Self::Config = ();
+
+    const SAMPLE_CONFIG: Self::Config = ();
+
+    const SAMPLE_ANSWER_A: Self::AnswerA = 143;
+
+    const SAMPLE_ANSWER_B: Self::AnswerB = 123;
+
+    fn parse(input: &str) -> ParseResult<Self::Parsed<'_>> {
         let page_order: Vec<(u64, u64)> = input
             .lines()
-            .take_while(|&line| line != "")
+            .take_while(|&line| !line.is_empty())
             .map(|line| {
-                line.split("|")
-                    .filter_map(|num| num.parse::<u64>().ok())
-                    .collect_tuple()
-                    .expect("Could not parse tuple")
+                let (before, after) = line
+                    .split_once('|')
+                    .ok_or_else(|| ParseError::missing(input, line, "`|`"))?;
+                Ok((parse::number(input, before)?, parse::number(input, after)?))
             })
-            .collect_vec();
+            .collect::<ParseResult<_>>()?;
         let updates: Vec<Vec<u64>> = input
             .lines()
-            .skip_while(|&line| line != "")
-            .into_iter()
+            .skip_while(|&line| !line.is_empty())
+            .skip(1)
+            .filter(|update| !update.is_empty())
             .map(|update| {
                 update
                     .split(",")
-                    .filter_map(|num| num.parse::<u64>().ok())
+                    .map(|num| parse::number(input, num))
                     .collect()
             })
-            .filter(|vec: &Vec<u64>| !vec.is_empty())
-            .collect();
+            .collect::<ParseResult<_>>()?;
         Ok((page_order, updates))
     }
 
-    fn part_a(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
+    fn part_a(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerA> {
         let (page_order, updates) = input;
         Ok(updates
             .iter()
-            .filter(|update| is_correctly_ordered(update, &page_order))
+            .filter(|update| is_correctly_ordered(update, page_order))
             .map(|update| get_middle(update))
             .sum())
     }
 
-    fn part_b(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
+    fn part_b(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerB> {
         let (page_order, updates) = input;
-        let lookup = page_order.clone().into_iter().into_group_map();
+        let lookup = page_order.iter().copied().into_group_map();
         Ok(updates
             .iter()
-            .filter(|update| !is_correctly_ordered(update, &page_order))
+            .filter(|update| !is_correctly_ordered(update, page_order))
             .map(|update| fix(update, &lookup))
             .map(|update| get_middle(&update))
             .sum())
@@ -85,8 +93,8 @@ fn get_middle(update: &[u64]) -> u64 {
 
 fn fix(update: &[u64], lookup: &HashMap<u64, Vec<u64>>) -> Vec<u64> {
     update
-        .to_vec()
-        .into_iter()
+        .iter()
+        .copied()
         .sorted_unstable_by(|a, b| ordering(a, b, lookup))
         .collect_vec()
 }
@@ -1,7 +1,11 @@
-use std::{collections::HashSet, usize};
+use std::collections::HashSet;
 
 use aoc_2024::*;
-use dimensions_2::{unsigned::{Dimensions, Point}, Direction};
+use dimensions_2::{
+    grid::{Grid, Tile},
+    unsigned::{Dimensions, Point},
+    Direction,
+};
 
 aoc!(Day6);
 
@@ -74,67 +78,80 @@ impl Guard {
 }
 
 impl Solution<Self> for Day6 {
-    type Parsed = (Dimensions, HashSet<Point>, Guard);
+    type Parsed<'input> = (Dimensions, HashSet<Point>, Guard);
 
-    type Answer = usize;
+    type AnswerA = usize;
 
-    const SAMPLE_ANSWER_A: Self::Answer = 41;
+    type AnswerB = usize;
 
-    const SAMPLE_ANSWER_B: Self::Answer = 6;
+    type Config = ();
 
-    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
-        let mut obstacles: HashSet<Point> = HashSet::new();
-        let mut guard: Guard = Guard {
-            position: Point::default(),
-            direction: Direction::Up,
+    const CONFIG: Self::Config = ();
+
+    const SAMPLE_CONFIG: Self::Config = ();
+
+    const SAMPLE_ANSWER_A: Self::AnswerA = 41;
+
+    const SAMPLE_ANSWER_B: Self::AnswerB = 6;
+
+    fn parse(input: &str) -> ParseResult<Self::Parsed<'_>> {
+        let (grid, markers) = Grid::parse_with(input, |ch, _| match ch {
+            '.' => Some(Tile::Plain(false)),
+            '#' => Some(Tile::Plain(true)),
+            '^' | '>' | 'v' | '<' => Some(Tile::Marker(false)),
+            _ => None,
+        })?;
+        let (marker, position) = markers.single_of(input, &['^', '>', 'v', '<'], "a guard")?;
+        let direction = match marker {
+            '^' => Direction::Up,
+            '>' => Direction::Right,
+            'v' => Direction::Down,
+            _ => Direction::Left,
+        };
+        let guard = Guard {
+            position,
+            direction,
         };
-        let dimensions = Dimensions::new(
-            input.lines().next().expect("No line").len(),
-            input.lines().count(),
-        );
-        input.lines().enumerate().for_each(|(y, line)| {
-            line.chars().enumerate().for_each(|(x, c)| match c {
-                '#' => {
-                    obstacles.insert(Point::new(x, y));
-                }
-                '>' => {
-                    guard.direction = Direction::Right;
-                    guard.position = Point::new(x, y);
-                }
-                '<' => {
-                    guard.direction = Direction::Left;
-                    guard.position = Point::new(x, y);
-                }
-                '^' => {
-                    guard.direction = Direction::Up;
-                    guard.position = Point::new(x, y);
-                }
-                'v' => {
-                    guard.direction = Direction::Down;
-                    guard.position = Point::new(x, y);
-                }
-                _ => (),
-            });
-        });
+        let dimensions = grid.dimensions();
+        let obstacles = grid.positions_of(&true).collect();
         Ok((dimensions, obstacles, guard))
     }
 
-    fn part_a(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
-        let (dimensions, obstacles, mut guard) = input;
-        let mut visited: HashSet<Point> = HashSet::new();
-        guard.patrol(dimensions, &obstacles, &mut visited);
-        Ok(visited.len())
+    fn part_a(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerA> {
+        Ok(patrolled(input).len())
     }
 
-    fn part_b(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
-        let (dimensions, obstacles, guard) = input;
-        let mut path_guard = guard;
-        let mut visited: HashSet<Point> = HashSet::new();
-        path_guard.patrol(dimensions, &obstacles, &mut visited);
-        Ok(visited.iter().filter(|new_obstacle| {
-            let mut new_obstacles = obstacles.clone();
-            new_obstacles.insert(**new_obstacle);
-            guard.detect_loop(dimensions, &new_obstacles)
-        }).count())
+    fn part_b(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerB> {
+        let visited = patrolled(input);
+        Ok(count_loops(input, &visited))
     }
+
+    fn solve_both(
+        input: &Self::Parsed<'_>,
+        _: &Self::Config,
+    ) -> Option<anyhow::Result<(Self::AnswerA, Self::AnswerB)>> {
+        let visited = patrolled(input);
+        Some(Ok((visited.len(), count_loops(input, &visited))))
+    }
+}
+
+fn patrolled(
+    (dimensions, obstacles, guard): &(Dimensions, HashSet<Point>, Guard),
+) -> HashSet<Point> {
+    let mut path_guard = *guard;
+    let mut visited: HashSet<Point> = HashSet::new();
+    path_guard.patrol(*dimensions, obstacles, &mut visited);
+    visited
+}
+
+// Only a cell on the patrol can turn the guard, so those are the only places worth blocking.
+fn count_loops(
+    (dimensions, obstacles, guard): &(Dimensions, HashSet<Point>, Guard),
+    visited: &HashSet<Point>,
+) -> usize {
+    visited.iter().filter(|new_obstacle| {
+        let mut new_obstacles = obstacles.clone();
+        new_obstacles.insert(**new_obstacle);
+        guard.detect_loop(*dimensions, &new_obstacles)
+    }).count()
 }
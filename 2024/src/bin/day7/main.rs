@@ -1,47 +1,53 @@
-use std::usize;
-
 use aoc_2024::*;
-use itertools::Itertools;
+use parse::Reason;
 
 aoc!(Day7);
 
 impl Solution<Self> for Day7 {
-    type Parsed = Vec<(usize, Vec<usize>)>;
+    type Parsed<'input> = Vec<(usize, Vec<usize>)>;
 
-    type Answer = usize;
+    type AnswerA = usize;
 
-    const SAMPLE_ANSWER_A: Self::Answer = 3749;
+    type AnswerB = usize;
 
-    const SAMPLE_ANSWER_B: Self::Answer = 11387;
+    type Config = ();
 
-    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
-        Ok(input
+    const CONFIG: Self::Config = ();
+
+    const SAMPLE_CONFIG: Self::Config = ();
+
+    const SAMPLE_ANSWER_A: Self::AnswerA = 3749;
+
+    const SAMPLE_ANSWER_B: Self::AnswerB = 11387;
+
+    fn parse(input: &str) -> ParseResult<Self::Parsed<'_>> {
+        input
             .lines()
             .map(|line| {
-                let mut split_colon = line.split(":");
-                let result = split_colon
-                    .next()
-                    .expect("Could not split at colon")
-                    .trim()
-                    .parse()
-                    .expect("Could not parse to usize");
-                let operators = split_colon
-                    .next()
-                    .expect("No operators")
-                    .trim()
+                let (result, operators) = line
+                    .split_once(':')
+                    .ok_or_else(|| ParseError::missing(input, line, "`:`"))?;
+                // The solvers divide by every operand and need at least one.
+                let operators: Vec<usize> = operators
                     .split_whitespace()
-                    .map(|operators| {
-                        operators
-                            .parse()
-                            .expect("Could not parse operator to usize")
+                    .map(|operator| match parse::number(input, operator)? {
+                        0 => Err(ParseError::at(
+                            input,
+                            operator,
+                            Reason::Invalid("a positive operand"),
+                        )),
+                        operand => Ok(operand),
                     })
-                    .collect_vec();
-                (result, operators)
+                    .collect::<ParseResult<_>>()?;
+                if operators.is_empty() {
+                    return Err(ParseError::missing(input, line, "an operand"));
+                }
+                Ok((parse::number(input, result.trim())?, operators))
             })
-            .collect_vec())
+            .collect()
     }
 
-    fn part_a(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
+    fn part_a(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerA> {
         Ok(input
             .iter()
             .filter(|(target, operators)| recursive(Some(*target), operators, operators.len() - 1))
@@ -49,7 +55,7 @@ impl Solution<Self> for Day7 {
             .sum())
     }
 
-    fn part_b(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
+    fn part_b(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerB> {
         Ok(input
             .iter()
             .filter(|(target, operators)| {
@@ -74,7 +80,7 @@ fn recursive(target: Option<usize>, numbers: &[usize], index: usize) -> bool {
         is_solved = recursive(Some(target / operand), numbers, index - 1);
     }
 
-    return is_solved || recursive(target.checked_sub(operand), numbers, index - 1);
+    is_solved || recursive(target.checked_sub(operand), numbers, index - 1)
 }
 
 fn recursive_with_concat(target: Option<usize>, numbers: &[usize], index: usize) -> bool {
@@ -105,7 +111,7 @@ fn recursive_with_concat(target: Option<usize>, numbers: &[usize], index: usize)
     }
 
 
-    return is_solved || recursive_with_concat(target.checked_sub(operand), numbers, index - 1);
+    is_solved || recursive_with_concat(target.checked_sub(operand), numbers, index - 1)
 }
 
 fn get_first_digits(num: usize, num_digits: u32) -> usize {
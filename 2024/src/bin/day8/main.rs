@@ -1,6 +1,6 @@
 use std::{
     collections::{HashMap, HashSet},
-    isize,
+    iter::successors,
 };
 
 use aoc_2024::*;
@@ -15,67 +15,49 @@ trait ExtendLine {
 }
 
 impl ExtendLine for Line {
-
     fn double_from_ends(&self) -> Vec<Point> {
-        let (x1, y1) = self.get_start().get();
-        let (x2, y2) = self.get_end().get();
-
-        let dx = x2 as isize - x1 as isize;
-        let dy = y2 as isize - y1 as isize;
-
-        let mut result = Vec::new();
-
-        if let (Some(new_x1), Some(new_y1)) = (x2.checked_add_signed(dx), y2.checked_add_signed(dy))
-        {
-            result.push(Point::new(new_x1, new_y1));
-        }
-
-        if let (Some(new_x2), Some(new_y2)) =
-            (x1.checked_add_signed(-dx), y1.checked_add_signed(-dy))
-        {
-            result.push(Point::new(new_x2, new_y2));
-        };
-
-        result
+        let delta = self.get_end() - self.get_start();
+        [
+            self.get_end().checked_add(delta, None),
+            self.get_start().checked_add(-delta, None),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
     }
 
     fn extend_distances(&self, dimensions: Dimensions) -> Vec<Point> {
-        let (x1, y1) = self.get_start().get();
-        let (x2, y2) = self.get_end().get();
-
-        let dx = x2 as isize - x1 as isize;
-        let dy = y2 as isize - y1 as isize;
+        let delta = self.get_end() - self.get_start();
+        let backwards = successors(Some(self.get_start()), |antinode| {
+            antinode.checked_add(-delta, Some(dimensions))
+        });
+        let forwards = successors(Some(self.get_end()), |antinode| {
+            antinode.checked_add(delta, Some(dimensions))
+        });
+        backwards.chain(forwards).collect()
+    }
+}
 
-        let mut result = Vec::new();
+impl Solution<Self> for Day8 {
+    type Parsed<'input> = (Dimensions, HashMap<char, Vec<Point>>);
 
-        let mut antinode = self.get_start();
-        while dimensions.is_within_bounds_exclusive(antinode) {
-            result.push(antinode);
-            antinode += (-dx, -dy);
-        }
+    type AnswerA = usize;
 
-        antinode = self.get_end();
-        while dimensions.is_within_bounds_exclusive(antinode) {
-            result.push(antinode);
-            antinode += (dx, dy);
-        }
+    type AnswerB = usize;
 
-        result
-    }
-}
+    type Config = ();
 
-impl Solution<Self> for Day8 {
-    type Parsed = (Dimensions, HashMap<char, Vec<Point>>);
+    const CONFIG: Self::Config = ();
 
-    type Answer = usize;
+    const SAMPLE_CONFIG: Self::Config = ();
 
-    const SAMPLE_ANSWER_A: Self::Answer = 14;
+    const SAMPLE_ANSWER_A: Self::AnswerA = 14;
 
-    const SAMPLE_ANSWER_B: Self::Answer = 34;
+    const SAMPLE_ANSWER_B: Self::AnswerB = 34;
 
-    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
+    fn parse(input: &str) -> ParseResult<Self::Parsed<'_>> {
         let dimension = Dimensions::new(
-            input.lines().next().expect("No lines").len(),
+            input.lines().next().map_or(0, str::len),
             input.lines().count(),
         );
         Ok((
@@ -90,32 +72,31 @@ impl Solution<Self> for Day8 {
                             letter if antenna.is_alphanumeric() => Some((letter, Point::new(x, y))),
                             _ => None,
                         })
-                        .into_iter()
                 })
                 .into_group_map(),
         ))
     }
 
-    fn part_a(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
+    fn part_a(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerA> {
         let (dimension, groups) = input;
-        let lines = transform_to_lines(&groups);
+        let lines = transform_to_lines(groups);
         let antinodes: HashSet<Point> = lines
-            .iter()
-            .flat_map(|(_, lines)| lines)
+            .values()
+            .flatten()
             .flat_map(|line| line.double_from_ends())
             .filter(|point| dimension.is_within_bounds_exclusive(*point))
             .collect();
         Ok(antinodes.len())
     }
 
-    fn part_b(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
+    fn part_b(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerB> {
         let (dimension, groups) = input;
 
-        let lines = transform_to_lines(&groups);
+        let lines = transform_to_lines(groups);
         let antinodes: HashSet<Point> = lines
-            .iter()
-            .flat_map(|(_, lines)| lines)
-            .flat_map(|line| line.extend_distances(dimension))
+            .values()
+            .flatten()
+            .flat_map(|line| line.extend_distances(*dimension))
             .collect();
 
         Ok(antinodes.len())
@@ -4,27 +4,36 @@ use std::{
 };
 
 use aoc_2024::*;
+#[cfg(test)]
+use dimensions_2::{grid::Grid, unsigned::Dimensions};
 use itertools::Itertools;
 
 aoc!(Day9);
 
 impl Solution<Self> for Day9 {
-    type Parsed = Vec<Option<usize>>;
+    type Parsed<'input> = Vec<Option<usize>>;
 
-    type Answer = usize;
+    type AnswerA = usize;
 
-    const SAMPLE_ANSWER_A: Self::Answer = 1928;
+    type AnswerB = usize;
 
-    const SAMPLE_ANSWER_B: Self::Answer = 2858;
+    type Config = ();
 
-    fn parse(input: &str) -> anyhow::Result<Self::Parsed> {
+    const CONFIG: Self::Config = ();
+
+    const SAMPLE_CONFIG: Self::Config = ();
+
+    const SAMPLE_ANSWER_A: Self::AnswerA = 1928;
+
+    const SAMPLE_ANSWER_B: Self::AnswerB = 2858;
+
+    fn parse(input: &str) -> ParseResult<Self::Parsed<'_>> {
         Ok(input
             .chars()
             .enumerate()
-            .filter(|(_, char)| char.is_numeric())
-            .flat_map(|(i, char)| {
+            .filter_map(|(i, char)| Some((i, char.to_digit(10)?)))
+            .flat_map(|(i, len)| {
                 let mut blocks = Vec::new();
-                let len = char.to_digit(10).expect("Could not convert char to digit");
                 if i % 2 == 1 {
                     for _ in 0..len {
                         blocks.push(None);
@@ -40,7 +49,7 @@ impl Solution<Self> for Day9 {
             .collect_vec())
     }
 
-    fn part_a(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
+    fn part_a(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerA> {
         let mut new_vec = input.clone();
         move_somes_to_start(&mut new_vec);
         Ok(new_vec
@@ -51,8 +60,8 @@ impl Solution<Self> for Day9 {
             .sum())
     }
 
-    fn part_b(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
-        let result = move_files_to_left(input);
+    fn part_b(input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerB> {
+        let result = move_files_to_left(input.clone());
         Ok(result
             .iter()
             .enumerate()
@@ -62,7 +71,7 @@ impl Solution<Self> for Day9 {
     }
 }
 
-fn move_somes_to_start(parsed: &mut Vec<Option<usize>>) {
+fn move_somes_to_start(parsed: &mut [Option<usize>]) {
     let mut left = 0;
     let mut right = parsed.len();
 
@@ -106,7 +115,7 @@ fn move_files_to_left(mut blocks: Vec<Option<usize>>) -> Vec<Option<usize>> {
                         let remaining_size = size - file_size;
                         free_space_map
                             .entry(remaining_size)
-                            .or_insert_with(BinaryHeap::new)
+                            .or_default()
                             .push(Reverse(start + file_size));
                     }
                 }
@@ -117,11 +126,11 @@ fn move_files_to_left(mut blocks: Vec<Option<usize>>) -> Vec<Option<usize>> {
     blocks
 }
 
-fn get_start_of_span<'a>(
-    heap_map: &'a mut HashMap<usize, BinaryHeap<Reverse<usize>>>,
+fn get_start_of_span(
+    heap_map: &mut HashMap<usize, BinaryHeap<Reverse<usize>>>,
     span_size: usize,
     start_index: usize
-) -> Option<(usize, &'a mut BinaryHeap<Reverse<usize>>)> {
+) -> Option<(usize, &mut BinaryHeap<Reverse<usize>>)> {
     heap_map
         .iter_mut()
         .filter(|(size, _)| **size >= span_size)
@@ -132,21 +141,21 @@ fn get_start_of_span<'a>(
         .map(|(size, heap)| (*size, heap)) // Return size and mutable heap reference
 }
 
-fn free_space_heapify(blocks: &Vec<Option<usize>>) -> HashMap<usize, BinaryHeap<Reverse<usize>>> {
+fn free_space_heapify(blocks: &[Option<usize>]) -> HashMap<usize, BinaryHeap<Reverse<usize>>> {
     let mut free_space_map: HashMap<usize, BinaryHeap<Reverse<usize>>> = HashMap::new();
 
     let mut current_size = 0;
     let mut span_start = None;
-    for i in 0..blocks.len() {
-        if blocks[i].is_none() {
+    for (i, block) in blocks.iter().enumerate() {
+        if block.is_none() {
             if current_size == 0 {
                 span_start = Some(i);
             }
             current_size += 1;
-        } else if blocks[i].is_some() && span_start.is_some() {
+        } else if block.is_some() && span_start.is_some() {
             free_space_map
                 .entry(current_size)
-                .or_insert_with(BinaryHeap::new)
+                .or_default()
                 .push(Reverse(span_start.unwrap()));
             current_size = 0;
             span_start = None;
@@ -167,9 +176,30 @@ fn free_space_heapify(blocks: &Vec<Option<usize>>) -> HashMap<usize, BinaryHeap<
     free_space_map
 }
 
-fn print_file_space(vec: &[Option<usize>]) {
-    vec.iter().for_each(|thing| match thing {
-        Some(x) => print!("{}", x),
-        None => print!("."),
-    });
+/// The blocks as the puzzle draws them, file IDs past 9 keeping only their last digit.
+#[cfg(test)]
+fn disk_map(blocks: &[Option<usize>]) -> Grid<Option<char>> {
+    Grid::from_fn(Dimensions::new(blocks.len(), 1), |point| {
+        blocks[point.get().0].and_then(|id| char::from_digit((id % 10) as u32, 10))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_2024::Solution;
+
+    use crate::{disk_map, move_files_to_left, Day9};
+
+    #[test]
+    fn draws_the_sample_before_and_after_moving_files() {
+        let blocks = Day9::parse(include_str!("sample.txt")).unwrap();
+        assert_eq!(
+            disk_map(&blocks).render().to_string(),
+            "00...111...2...333.44.5555.6666.777.888899\n"
+        );
+        assert_eq!(
+            disk_map(&move_files_to_left(blocks)).render().to_string(),
+            "00992111777.44.333....5555.6666.....8888..\n"
+        );
+    }
 }
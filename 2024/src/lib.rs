@@ -1,107 +1,45 @@
-use std::fmt::{Debug, Display};
-
-pub mod dimensions_2;
-
-pub trait SolutionData {
-    const INPUT: &'static str;
-    const SAMPLE_INPUT: &'static str;
-}
-
-pub trait Solution<T: SolutionData> {
-    type Parsed: Debug + Clone;
-    type Answer: Debug + Display + PartialEq;
-    const SAMPLE_ANSWER_A: Self::Answer;
-    const SAMPLE_ANSWER_B: Self::Answer;
-
-    fn parse(input: &str) -> anyhow::Result<Self::Parsed>;
-    fn part_a(input: Self::Parsed) -> anyhow::Result<Self::Answer>;
-    fn part_b(input: Self::Parsed) -> anyhow::Result<Self::Answer>;
-
-    fn part_a_test(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
-        Self::part_a(input)
-    }
-
-    fn part_b_test(input: Self::Parsed) -> anyhow::Result<Self::Answer> {
-        Self::part_b(input)
-    }
-
-    fn test_part_a() -> anyhow::Result<()> {
-        assert_eq!(
-            Self::parse(T::SAMPLE_INPUT).and_then(Self::part_a_test)?,
-            Self::SAMPLE_ANSWER_A
-        );
-        let shared = Self::parse(T::INPUT)?;
-        println!("a: {}", Self::part_a(shared)?);
-        Ok(())
-    }
-
-    fn test_part_b() -> anyhow::Result<()> {
-        assert_eq!(
-            Self::parse(T::SAMPLE_INPUT).and_then(Self::part_b_test)?,
-            Self::SAMPLE_ANSWER_B
-        );
-        let shared = Self::parse(T::INPUT)?;
-        println!("b: {}", Self::part_b(shared)?);
-        Ok(())
-    }
-
-    fn main() -> anyhow::Result<()> {
-        let input = time("Parse", || Self::parse(T::INPUT))?;
-        let arg = std::env::args().nth(1);
-        match arg.as_deref() {
-            Some("a") => {
-                let a = time("Part a", || Self::part_a(input))?;
-                println!("a: {a}");
-            }
-            Some("b") => {
-                let b = time("Part b", || Self::part_b(input))?;
-                println!("b: {b}");
-            }
-            _ => {
-                let a = time("Part a", || Self::part_a(input.clone()))?;
-                let b = time("Part b", || Self::part_b(input.clone()))?;
-                println!("a: {a}");
-                println!("b: {b}");
-            }
-        }
-        Ok(())
-    }
-}
-
-fn time<T>(tag: &str, f: impl FnOnce() -> T) -> T {
-    let start = std::time::Instant::now();
-    let ans = f();
-    println!("{tag} took {:?}", start.elapsed());
-    ans
-}
-
-#[macro_export]
-macro_rules! aoc {
-    ($day:ident) => {
-        struct $day;
-
-        impl SolutionData for $day {
-            const INPUT: &'static str = include_str!("input.txt");
-            const SAMPLE_INPUT: &'static str = include_str!("sample.txt");
-        }
-
-        #[cfg(test)]
-        mod tests {
-            use super::*;
-
-            #[test]
-            fn a() -> anyhow::Result<()> {
-                $day::test_part_a()
-            }
-
-            #[test]
-            fn b() -> anyhow::Result<()> {
-                $day::test_part_b()
-            }
-        }
-
-        fn main() -> anyhow::Result<()> {
-            $day::main()
-        }
-    };
+//! Solutions to Advent of Code 2024, one binary per day under `src/bin`, built on `aoc-core`.
+
+// Lets the days' `use aoc_2024::*;` resolve when they are compiled in below.
+extern crate self as aoc_2024;
+
+pub use aoc_core::*;
+
+// The days are also compiled into the library so their `aoc!` registrations link into the `aoc`
+// runner. Their tests already run with the binaries.
+#[cfg(not(test))]
+#[path = "bin"]
+mod days {
+    #[path = "day1/main.rs"]
+    mod day1;
+    #[path = "day10/main.rs"]
+    mod day10;
+    #[path = "day11/main.rs"]
+    mod day11;
+    #[path = "day12/main.rs"]
+    mod day12;
+    #[path = "day13/main.rs"]
+    mod day13;
+    #[path = "day14/main.rs"]
+    mod day14;
+    #[path = "day15/main.rs"]
+    mod day15;
+    #[path = "day16/main.rs"]
+    mod day16;
+    #[path = "day2/main.rs"]
+    mod day2;
+    #[path = "day3/main.rs"]
+    mod day3;
+    #[path = "day4/main.rs"]
+    mod day4;
+    #[path = "day5/main.rs"]
+    mod day5;
+    #[path = "day6/main.rs"]
+    mod day6;
+    #[path = "day7/main.rs"]
+    mod day7;
+    #[path = "day8/main.rs"]
+    mod day8;
+    #[path = "day9/main.rs"]
+    mod day9;
 }
@@ -0,0 +1,365 @@
+use std::{
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::{bail, Context};
+use aoc_core::{
+    answers::Answers,
+    cache::{Cache, Fetcher},
+    cli::Format,
+    page,
+    record::{RecordWriter, RunRecord},
+    runner::{registered_days, DayRun, Part, PartRun},
+    scaffold::{self, Template},
+};
+
+// Linking a year pulls in its days' `aoc!` registrations, add new years to Cargo.toml and here.
+use aoc_2024 as _;
+
+const USAGE: &str = "Usage: aoc [--input-dir <dir>] [--format <text|json|csv>] \
+                     <all | <year> [<day> | <from>..=<to> | all] [a|b|both]>
+       aoc fetch [--list] [--sample <block>[,<block>...]] [--refresh] <year> <day>
+       aoc new [--template <lines|grid|numbers|blocks>] <year> <day>";
+
+#[derive(Debug)]
+struct Selection {
+    year: Option<u16>,
+    days: RangeInclusive<u8>,
+    part: Part,
+    input_dir: Option<PathBuf>,
+    format: Format,
+}
+
+impl Selection {
+    fn from_args(mut args: impl Iterator<Item = String>) -> anyhow::Result<Self> {
+        let mut input_dir = None;
+        let mut format = Format::Text;
+        let mut positional = Vec::new();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input-dir" => {
+                    input_dir = Some(PathBuf::from(
+                        args.next().context("--input-dir requires a directory")?,
+                    ))
+                }
+                "--format" => {
+                    format = args.next().context("--format requires a format")?.parse()?
+                }
+                _ => positional.push(arg),
+            }
+        }
+
+        let mut positional = positional.into_iter();
+        let year = match positional.next().as_deref() {
+            Some("all") => None,
+            Some(year) => Some(
+                year.parse()
+                    .with_context(|| format!("Invalid year `{year}`"))?,
+            ),
+            None => bail!(USAGE),
+        };
+        let days = match positional.next() {
+            Some(days) => parse_days(&days)?,
+            None => 1..=25,
+        };
+        let part = match positional.next() {
+            Some(part) => part.parse()?,
+            None => Part::Both,
+        };
+        if let Some(extra) = positional.next() {
+            bail!("Unexpected argument `{extra}`\n{USAGE}");
+        }
+
+        Ok(Self {
+            year,
+            days,
+            part,
+            input_dir,
+            format,
+        })
+    }
+}
+
+fn parse_days(days: &str) -> anyhow::Result<RangeInclusive<u8>> {
+    let parse = |day: &str| {
+        day.parse::<u8>()
+            .with_context(|| format!("Invalid day `{day}`"))
+    };
+    if days == "all" {
+        Ok(1..=25)
+    } else if let Some((from, to)) = days.split_once("..=") {
+        Ok(parse(from)?..=parse(to)?)
+    } else if let Some((from, to)) = days.split_once("..") {
+        Ok(parse(from)?..=parse(to)?.saturating_sub(1))
+    } else {
+        let day = parse(days)?;
+        Ok(day..=day)
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let mut args = std::env::args().skip(1).peekable();
+    if args.next_if(|arg| arg == "fetch").is_some() {
+        return fetch(args);
+    }
+    if args.next_if(|arg| arg == "new").is_some() {
+        return new(args);
+    }
+    let selection = Selection::from_args(args)?;
+    let days = registered_days()
+        .into_iter()
+        .filter(|day| selection.year.is_none_or(|year| year == day.year))
+        .filter(|day| selection.days.contains(&day.day))
+        .collect::<Vec<_>>();
+    if days.is_empty() {
+        bail!("No registered days match the selection");
+    }
+
+    let mut records = RecordWriter::new(selection.format);
+    let mut rows = vec![["year", "day", "parse", "a", "time", "b", "time"].map(String::from)];
+    let mut total = Duration::ZERO;
+    let mut failures = Vec::new();
+    for day in days {
+        match (day.run)(selection.input_dir.as_deref(), selection.part) {
+            Ok(run) => {
+                total += run_time(&run);
+                for (part, part_run) in [("a", &run.a), ("b", &run.b)] {
+                    if let Some(Err(err)) = part_run.as_ref().map(|part_run| &part_run.answer) {
+                        failures.push(format!("{} day {} part {part}: {err}", run.year, run.day));
+                    }
+                }
+                match selection.format {
+                    Format::Text => rows.push(summary_row(&run)),
+                    Format::Json | Format::Csv => records.write(&RunRecord::from_run(&run))?,
+                }
+            }
+            Err(err) => {
+                failures.push(format!("{} day {}: {err:#}", day.year, day.day));
+                match selection.format {
+                    Format::Text => rows.push([
+                        day.year.to_string(),
+                        day.day.to_string(),
+                        "error".to_string(),
+                        String::new(),
+                        String::new(),
+                        String::new(),
+                        String::new(),
+                    ]),
+                    Format::Json | Format::Csv => records.write(&RunRecord::failed(
+                        day.year,
+                        day.day,
+                        selection.part,
+                        &err,
+                    ))?,
+                }
+            }
+        }
+    }
+    if selection.format == Format::Text {
+        print_table(&rows);
+        println!("Total {total:?}");
+    }
+
+    if !failures.is_empty() {
+        failures.iter().for_each(|failure| eprintln!("{failure}"));
+        bail!("{} run(s) failed", failures.len());
+    }
+    Ok(())
+}
+
+/// Fills the puzzle cache with a day's input and sample, reusing whatever is already there.
+///
+/// The sample defaults to the page's first code block, `--list` shows every block with its index
+/// and `--sample` picks the blocks to use instead.
+fn fetch(mut args: impl Iterator<Item = String>) -> anyhow::Result<()> {
+    let mut list = false;
+    let mut refresh = false;
+    let mut blocks = None;
+    let mut positional = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--list" => list = true,
+            "--refresh" => refresh = true,
+            "--sample" => {
+                let indices = args.next().context("--sample requires block indices")?;
+                blocks = Some(
+                    indices
+                        .split(',')
+                        .map(|index| {
+                            index
+                                .parse()
+                                .with_context(|| format!("Invalid block `{index}`"))
+                        })
+                        .collect::<anyhow::Result<Vec<usize>>>()?,
+                );
+            }
+            _ => positional.push(arg),
+        }
+    }
+    let [year, day] = positional.as_slice() else {
+        bail!(USAGE);
+    };
+    let year = year
+        .parse()
+        .with_context(|| format!("Invalid year `{year}`"))?;
+    let day = day
+        .parse()
+        .with_context(|| format!("Invalid day `{day}`"))?;
+
+    let cache = Cache::from_env()?;
+    let fetcher = Fetcher::from_env()?;
+    cache.input(&fetcher, year, day)?;
+    println!(
+        "Input saved to {}",
+        cache.day_dir(year, day).join("input.txt").display()
+    );
+
+    let has_sample = cache.has_sample(year, day)?;
+    if !list && blocks.is_none() && !refresh && has_sample {
+        return Ok(());
+    }
+    let puzzle_page = cache.puzzle_page(&fetcher, year, day, refresh)?;
+    let code_blocks = page::code_blocks(&puzzle_page);
+    if list {
+        for (index, block) in code_blocks.iter().enumerate() {
+            println!("[{index}]\n{}", block.trim_end());
+        }
+        for (part, candidates) in ["a", "b"].iter().zip(page::answer_candidates(&puzzle_page)) {
+            println!("Part {part} answer candidates: {}", candidates.join(", "));
+        }
+        return Ok(());
+    }
+    if blocks.is_some() || !has_sample {
+        if code_blocks.is_empty() {
+            println!("No code block on the puzzle page to use as the sample");
+        } else {
+            let sample = page::sample_from(&code_blocks, blocks.as_deref().unwrap_or(&[0]))?;
+            cache.save_sample(year, day, &sample)?;
+            println!("Sample saved to {}", cache.sample_path(year, day).display());
+        }
+    }
+    let proposed = page::proposed_sample_answers(&puzzle_page);
+    let path = cache.sample_answers_path(year, day);
+    proposed.save(&path)?;
+    println!(
+        "Sample answers a = {}, b = {} proposed in {}",
+        proposed.a.as_deref().unwrap_or("?"),
+        proposed.b.as_deref().unwrap_or("?"),
+        path.display()
+    );
+    // A day made before part b was out only got part a's answer from `new`, so fill in the rest.
+    let day_dir = year_crate_dir(year)?
+        .join("src")
+        .join("bin")
+        .join(format!("day{day}"));
+    if day_dir.is_dir() {
+        let path = day_dir.join("sample_answers.toml");
+        let mut answers = Answers::load(&path)?;
+        if answers.fill(proposed) {
+            answers.save(&path)?;
+            println!("Sample answers merged into {}", path.display());
+        }
+    }
+    Ok(())
+}
+
+/// The year crates sit next to this one in the workspace.
+fn year_crate_dir(year: u16) -> anyhow::Result<PathBuf> {
+    Ok(Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .context("The runner is not in a workspace")?
+        .join(year.to_string()))
+}
+
+/// Scaffolds a day in the year's crate, seeded from the puzzle cache when it has the day.
+fn new(mut args: impl Iterator<Item = String>) -> anyhow::Result<()> {
+    let mut template = None;
+    let mut positional = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--template" => {
+                template = Some(
+                    args.next()
+                        .context("--template requires a template")?
+                        .parse::<Template>()?,
+                )
+            }
+            _ => positional.push(arg),
+        }
+    }
+    let [year, day] = positional.as_slice() else {
+        bail!(USAGE);
+    };
+    let year: u16 = year
+        .parse()
+        .with_context(|| format!("Invalid year `{year}`"))?;
+    let day = day
+        .parse()
+        .with_context(|| format!("Invalid day `{day}`"))?;
+    let crate_dir = year_crate_dir(year)?;
+    let cache = Cache::from_env().ok();
+    let written = scaffold::scaffold(&crate_dir, year, day, template, cache.as_ref())?;
+    for path in &written {
+        println!("Wrote {}", path.display());
+    }
+    if !written.iter().any(|path| path.ends_with("input.txt")) {
+        println!("No cached input yet, fetch it with `cargo run --bin aoc -- fetch {year} {day}`");
+    }
+    Ok(())
+}
+
+fn run_time(run: &DayRun) -> Duration {
+    run.parse
+        + run.a.as_ref().map_or(Duration::ZERO, |a| a.elapsed)
+        + run.b.as_ref().map_or(Duration::ZERO, |b| b.elapsed)
+}
+
+fn summary_row(run: &DayRun) -> [String; 7] {
+    let (a, a_time) = run.a.as_ref().map_or_else(Default::default, |a| {
+        (answer_cell(a), format!("{:?}", a.elapsed))
+    });
+    let (b, b_time) = run.b.as_ref().map_or_else(Default::default, |b| {
+        let time = if run.solved_together {
+            "with a".to_string()
+        } else {
+            format!("{:?}", b.elapsed)
+        };
+        (answer_cell(b), time)
+    });
+    [
+        run.year.to_string(),
+        run.day.to_string(),
+        format!("{:?}", run.parse),
+        a,
+        a_time,
+        b,
+        b_time,
+    ]
+}
+
+fn answer_cell(part: &PartRun) -> String {
+    match &part.answer {
+        Ok(answer) => answer.clone(),
+        Err(_) => "error".to_string(),
+    }
+}
+
+fn print_table(rows: &[[String; 7]]) {
+    let mut widths = [0; 7];
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    for row in rows {
+        let line = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        println!("{}", line.trim_end());
+    }
+}
@@ -0,0 +1,97 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+use serde::{Deserialize, Serialize};
+
+use crate::{input, runner::DayRun, SolutionData};
+
+/// Resolves the `answers.toml` kept next to the puzzle input for `T`, see [`input::input_path`].
+pub fn path<T: SolutionData + ?Sized>(input_dir: Option<&Path>) -> PathBuf {
+    input::input_path::<T>(input_dir).with_file_name("answers.toml")
+}
+
+/// Resolves the `sample_answers.toml` proposed from the puzzle page, kept next to `sample.txt`.
+pub fn sample_path<T: SolutionData + ?Sized>() -> PathBuf {
+    Path::new(T::MANIFEST_DIR)
+        .join("src")
+        .join("bin")
+        .join(format!("day{}", T::DAY))
+        .join("sample_answers.toml")
+}
+
+/// Answers to a day's puzzle input that the site confirmed, checked on every later run.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Answers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub a: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub b: Option<String>,
+}
+
+impl Answers {
+    /// Loads the answers at `path`, a missing file holds no answers yet.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(file) => toml::from_str(&file)
+                .with_context(|| format!("Could not parse answers {}", path.display())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => {
+                Err(err).with_context(|| format!("Could not read answers {}", path.display()))
+            }
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::write(path, toml::to_string(self)?)
+            .with_context(|| format!("Could not write answers {}", path.display()))
+    }
+
+    /// Takes the parts of `proposed` that have no answer yet, keeping the ones already there, and
+    /// tells whether any were added.
+    pub fn fill(&mut self, proposed: Self) -> bool {
+        let mut added = false;
+        for (answer, proposed) in [(&mut self.a, proposed.a), (&mut self.b, proposed.b)] {
+            if answer.is_none() && proposed.is_some() {
+                *answer = proposed;
+                added = true;
+            }
+        }
+        added
+    }
+
+    /// Fails every part of `run` whose answer differs from the recorded one.
+    pub fn verify(&self, run: &mut DayRun) {
+        for (part_run, expected) in [(&mut run.a, &self.a), (&mut run.b, &self.b)] {
+            let (Some(part_run), Some(expected)) = (part_run, expected) else {
+                continue;
+            };
+            if let Ok(answer) = &part_run.answer {
+                if answer != expected {
+                    part_run.answer = Err(format!(
+                        "{answer} differs from the recorded answer {expected}"
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Takes the answers of a successful `run` for the parts it ran, refusing to replace a
+    /// different recorded answer.
+    pub fn record(&mut self, run: &DayRun) -> anyhow::Result<()> {
+        for (tag, part_run, recorded) in [("a", &run.a, &mut self.a), ("b", &run.b, &mut self.b)] {
+            let Some(part_run) = part_run else {
+                continue;
+            };
+            let Ok(answer) = &part_run.answer else {
+                bail!("Part {tag} failed, nothing to record");
+            };
+            match recorded {
+                Some(recorded) if recorded != answer => bail!(
+                    "Part {tag} already has the answer {recorded} recorded, edit answers.toml to replace it"
+                ),
+                _ => *recorded = Some(answer.clone()),
+            }
+        }
+        Ok(())
+    }
+}
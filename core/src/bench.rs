@@ -0,0 +1,158 @@
+use std::{fmt::Display, path::Path, time::Duration};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BenchOptions {
+    /// Untimed runs before measuring, to warm caches and the allocator.
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup: 5,
+            iterations: 100,
+        }
+    }
+}
+
+/// Summary of repeated timings, stored in nanoseconds so baselines round-trip through JSON.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub stddev_ns: u64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        samples.sort_unstable();
+        let nanos = samples
+            .iter()
+            .map(|sample| sample.as_nanos() as f64)
+            .collect::<Vec<_>>();
+        let len = nanos.len().max(1) as f64;
+        let mean = nanos.iter().sum::<f64>() / len;
+        let variance = nanos.iter().map(|ns| (ns - mean).powi(2)).sum::<f64>() / len;
+        let median = match nanos.len() {
+            0 => 0.0,
+            n if n % 2 == 0 => (nanos[n / 2 - 1] + nanos[n / 2]) / 2.0,
+            n => nanos[n / 2],
+        };
+        Self {
+            min_ns: nanos.first().copied().unwrap_or_default() as u64,
+            median_ns: median as u64,
+            mean_ns: mean as u64,
+            stddev_ns: variance.sqrt() as u64,
+        }
+    }
+
+    /// Change of the median relative to `baseline` in percent, positive is slower.
+    pub fn regression(&self, baseline: &Stats) -> f64 {
+        (self.median_ns as f64 - baseline.median_ns as f64) / baseline.median_ns.max(1) as f64
+            * 100.0
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:?}  median {:?}  mean {:?}  stddev {:?}",
+            Duration::from_nanos(self.min_ns),
+            Duration::from_nanos(self.median_ns),
+            Duration::from_nanos(self.mean_ns),
+            Duration::from_nanos(self.stddev_ns),
+        )
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct BenchReport {
+    pub year: u16,
+    pub day: u8,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub a: Option<Stats>,
+    pub b: Option<Stats>,
+    /// Both parts came from one [`Solution::solve_both`](crate::Solution::solve_both) pass, timed
+    /// in `a`.
+    #[serde(default)]
+    pub solved_together: bool,
+}
+
+impl BenchReport {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let file = std::fs::read_to_string(path)
+            .with_context(|| format!("Could not read baseline {}", path.display()))?;
+        serde_json::from_str(&file)
+            .with_context(|| format!("Could not parse baseline {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::write(path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Could not write baseline {}", path.display()))
+    }
+
+    /// Renders one line per measured step, with the change against `baseline` when given.
+    pub fn render(&self, baseline: Option<&BenchReport>) -> String {
+        let steps = [
+            ("parse", Some(&self.parse), baseline.map(|base| &base.parse)),
+            (
+                if self.solved_together {
+                    "both"
+                } else {
+                    "part a"
+                },
+                self.a.as_ref(),
+                baseline.and_then(|base| base.a.as_ref()),
+            ),
+            (
+                "part b",
+                self.b.as_ref(),
+                baseline.and_then(|base| base.b.as_ref()),
+            ),
+        ];
+        let mut lines = vec![format!("Day {} ({} iterations)", self.day, self.iterations)];
+        for (tag, stats, base) in steps {
+            let Some(stats) = stats else {
+                continue;
+            };
+            let mut line = format!("{tag:<6}  {stats}");
+            if let Some(base) = base {
+                line.push_str(&format!("  ({:+.1}% vs baseline)", stats.regression(base)));
+            }
+            lines.push(line);
+        }
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_of_samples() {
+        let mut samples = [4, 1, 3, 2].map(Duration::from_nanos);
+        let stats = Stats::from_samples(&mut samples);
+        assert_eq!(
+            stats,
+            Stats {
+                min_ns: 1,
+                median_ns: 2,
+                mean_ns: 2,
+                stddev_ns: 1,
+            }
+        );
+        let slower = Stats {
+            median_ns: 3,
+            ..stats
+        };
+        assert_eq!(slower.regression(&stats), 50.0);
+    }
+}
@@ -0,0 +1,37 @@
+//! Support for the year crates' build scripts.
+
+use std::{env, fs, path::Path};
+
+/// Generates the files `aoc!` includes, so every year crate's `build.rs` is
+/// `fn main() { aoc_core::build::generate() }` with `aoc-core` as a build dependency.
+///
+/// Macros can't check whether a file exists, so for every `src/bin/day<N>` this writes
+/// `$OUT_DIR/sample_b/Day<N>.rs` holding either `Some(include_str!(".../sample_b.txt"))` or `None`.
+pub fn generate() {
+    println!("cargo:rerun-if-changed=src/bin");
+    let out_dir =
+        Path::new(&env::var("OUT_DIR").expect("OUT_DIR is set by cargo")).join("sample_b");
+    fs::create_dir_all(&out_dir).expect("Could not create sample_b directory");
+
+    let bin_dir =
+        Path::new(&env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is set by cargo"))
+            .join("src")
+            .join("bin");
+    for entry in fs::read_dir(&bin_dir)
+        .expect("Could not read src/bin")
+        .flatten()
+    {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let Some(day) = name.strip_prefix("day") else {
+            continue;
+        };
+        let sample_b = entry.path().join("sample_b.txt");
+        let contents = if sample_b.is_file() {
+            format!("Some(include_str!({:?}))", sample_b.display().to_string())
+        } else {
+            "None".to_string()
+        };
+        fs::write(out_dir.join(format!("Day{day}.rs")), contents)
+            .expect("Could not write sample_b include");
+    }
+}
@@ -0,0 +1,283 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+
+/// Environment variable pointing at the directory fetched puzzles are cached in.
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+/// Environment variable holding the adventofcode.com `session` cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable pointing the fetcher at another server, e.g. a local stand-in.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/NChitty/AdventOfCode";
+
+/// Puzzle inputs and samples fetched once and kept on disk as `<root>/<year>/day<N>/`.
+///
+/// A year's directory has the layout [`input::input_path`](crate::input::input_path) expects, so it
+/// can be passed as an input directory.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cache {
+    root: PathBuf,
+}
+
+impl Cache {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// The cache at [`CACHE_DIR_VAR`], falling back to `$XDG_CACHE_HOME/aoc` then `~/.cache/aoc`.
+    pub fn from_env() -> anyhow::Result<Self> {
+        if let Some(dir) = std::env::var_os(CACHE_DIR_VAR) {
+            return Ok(Self::new(dir));
+        }
+        if let Some(dir) = std::env::var_os("XDG_CACHE_HOME") {
+            return Ok(Self::new(Path::new(&dir).join("aoc")));
+        }
+        let home = std::env::var_os("HOME")
+            .with_context(|| format!("Set {CACHE_DIR_VAR} or HOME to locate the puzzle cache"))?;
+        Ok(Self::new(Path::new(&home).join(".cache").join("aoc")))
+    }
+
+    pub fn year_dir(&self, year: u16) -> PathBuf {
+        self.root.join(year.to_string())
+    }
+
+    pub fn day_dir(&self, year: u16, day: u8) -> PathBuf {
+        self.year_dir(year).join(format!("day{day}"))
+    }
+
+    /// The cached input, fetched and stored first when there is no copy yet.
+    pub fn input(&self, fetcher: &Fetcher, year: u16, day: u8) -> anyhow::Result<String> {
+        let path = self.day_dir(year, day).join("input.txt");
+        if let Some(input) = read_cached(&path)? {
+            return Ok(input);
+        }
+        let input = fetcher.input(year, day)?;
+        write_atomically(&path, &input)?;
+        Ok(input)
+    }
+
+    /// The cached puzzle page, fetched when there is no copy yet or when `refresh`ing it to pick
+    /// up part b once part a is solved.
+    pub fn puzzle_page(
+        &self,
+        fetcher: &Fetcher,
+        year: u16,
+        day: u8,
+        refresh: bool,
+    ) -> anyhow::Result<String> {
+        let path = self.day_dir(year, day).join("puzzle.html");
+        if !refresh {
+            if let Some(page) = read_cached(&path)? {
+                return Ok(page);
+            }
+        }
+        let page = fetcher.puzzle_page(year, day)?;
+        write_atomically(&path, &page)?;
+        Ok(page)
+    }
+
+    pub fn sample_path(&self, year: u16, day: u8) -> PathBuf {
+        self.day_dir(year, day).join("sample.txt")
+    }
+
+    /// Where the sample answers proposed from the puzzle page go, see
+    /// [`answers::sample_path`](crate::answers::sample_path).
+    pub fn sample_answers_path(&self, year: u16, day: u8) -> PathBuf {
+        self.day_dir(year, day).join("sample_answers.toml")
+    }
+
+    pub fn has_sample(&self, year: u16, day: u8) -> anyhow::Result<bool> {
+        Ok(read_cached(&self.sample_path(year, day))?.is_some())
+    }
+
+    pub fn save_sample(&self, year: u16, day: u8, sample: &str) -> anyhow::Result<()> {
+        write_atomically(&self.sample_path(year, day), sample)
+    }
+
+    /// Where [`submit`](crate::submit) keeps the answers tried for a day and the site's replies.
+    pub fn history_path(&self, year: u16, day: u8) -> PathBuf {
+        self.day_dir(year, day).join("submissions.toml")
+    }
+}
+
+/// An empty file counts as missing, so an interrupted download is fetched again.
+fn read_cached(path: &Path) -> anyhow::Result<Option<String>> {
+    match std::fs::read_to_string(path) {
+        Ok(contents) if contents.trim().is_empty() => Ok(None),
+        Ok(contents) => Ok(Some(contents)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err).with_context(|| format!("Could not read {}", path.display())),
+    }
+}
+
+/// Writes next to `path` and renames into place, so a failed write never leaves a partial file.
+pub(crate) fn write_atomically(path: &Path, contents: &str) -> anyhow::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Could not create {}", dir.display()))?;
+    }
+    let partial = path.with_extension("partial");
+    std::fs::write(&partial, contents)
+        .with_context(|| format!("Could not write {}", partial.display()))?;
+    std::fs::rename(&partial, path)
+        .with_context(|| format!("Could not move {} into place", partial.display()))
+}
+
+/// Fetches puzzle pages and inputs over HTTP with the user's session cookie.
+#[derive(Debug)]
+pub struct Fetcher {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Fetcher {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+        }
+    }
+
+    /// A fetcher for [`BASE_URL_VAR`], or adventofcode.com, using the cookie in [`SESSION_VAR`].
+    pub fn from_env() -> anyhow::Result<Self> {
+        let session = std::env::var(SESSION_VAR).with_context(|| {
+            format!("Set {SESSION_VAR} to your adventofcode.com session cookie")
+        })?;
+        let base_url = std::env::var(BASE_URL_VAR).unwrap_or_else(|_| BASE_URL.to_string());
+        Ok(Self::new(base_url, session))
+    }
+
+    pub fn puzzle_page(&self, year: u16, day: u8) -> anyhow::Result<String> {
+        self.get(&format!("/{year}/day/{day}"))
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> anyhow::Result<String> {
+        let input = self.get(&format!("/{year}/day/{day}/input"))?;
+        check_input(&input).with_context(|| format!("No input for {year} day {day}"))?;
+        Ok(input)
+    }
+
+    /// Posts an answer for part `level`, 1 or 2, returning the page the site replied with.
+    pub fn answer(&self, year: u16, day: u8, level: u8, answer: &str) -> anyhow::Result<String> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &level.to_string()), ("answer", answer)]);
+        read_response(&url, response)
+    }
+
+    fn get(&self, path: &str) -> anyhow::Result<String> {
+        let url = format!("{}{path}", self.base_url);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        read_response(&url, response)
+    }
+}
+
+fn read_response(
+    url: &str,
+    response: Result<ureq::Response, ureq::Error>,
+) -> anyhow::Result<String> {
+    let response = match response {
+        Ok(response) => response,
+        Err(ureq::Error::Status(status, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            match body
+                .lines()
+                .next()
+                .filter(|line| !line.trim_start().starts_with('<'))
+            {
+                Some(message) => bail!("{url} answered {status}: {}", message.trim()),
+                None => bail!("{url} answered {status}"),
+            }
+        }
+        Err(err) => return Err(err).with_context(|| format!("Could not fetch {url}")),
+    };
+    response
+        .into_string()
+        .with_context(|| format!("Could not read the response from {url}"))
+}
+
+/// Rejects bodies that can't be a puzzle input, such as the log in prompt or an HTML page.
+fn check_input(body: &str) -> anyhow::Result<()> {
+    let trimmed = body.trim_start();
+    if trimmed.is_empty() {
+        bail!("the response was empty");
+    }
+    if trimmed.starts_with('<') {
+        bail!("the response was an HTML page");
+    }
+    if trimmed.starts_with("Puzzle inputs differ by user") || trimmed.starts_with("Please ") {
+        bail!("{}", trimmed.trim_end());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stand_in::stand_in;
+
+    fn temp_cache(name: &str) -> Cache {
+        let root = std::env::temp_dir().join(format!("aoc-cache-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        Cache::new(root)
+    }
+
+    #[test]
+    fn fetches_input_once() -> anyhow::Result<()> {
+        let (url, server) = stand_in(vec![(200, "3   4\n4   3\n")]);
+        let fetcher = Fetcher::new(url, "secret");
+        let cache = temp_cache("fetches_input_once");
+        assert_eq!(cache.input(&fetcher, 2024, 1)?, "3   4\n4   3\n");
+        // The stand-in has stopped listening, so this only succeeds from the cache.
+        assert_eq!(cache.input(&fetcher, 2024, 1)?, "3   4\n4   3\n");
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2024/day/1/input "));
+        assert!(requests[0].contains("session=secret"));
+        Ok(())
+    }
+
+    #[test]
+    fn never_caches_a_bad_input() {
+        let (url, server) = stand_in(vec![
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+            ),
+            (200, ""),
+            (
+                200,
+                "<!DOCTYPE html>\n<html><body>500 Internal Server Error</body></html>",
+            ),
+        ]);
+        let fetcher = Fetcher::new(url, "secret");
+        let cache = temp_cache("never_caches_a_bad_input");
+        for _ in 0..3 {
+            assert!(cache.input(&fetcher, 2024, 2).is_err());
+            assert!(!cache.day_dir(2024, 2).join("input.txt").exists());
+        }
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn refreshes_page_on_request() -> anyhow::Result<()> {
+        let (url, server) = stand_in(vec![(200, "part a"), (200, "part a and b")]);
+        let fetcher = Fetcher::new(url, "secret");
+        let cache = temp_cache("refreshes_page_on_request");
+        assert_eq!(cache.puzzle_page(&fetcher, 2024, 3, false)?, "part a");
+        assert_eq!(cache.puzzle_page(&fetcher, 2024, 3, false)?, "part a");
+        assert_eq!(cache.puzzle_page(&fetcher, 2024, 3, true)?, "part a and b");
+        assert_eq!(server.join().unwrap().len(), 2);
+        Ok(())
+    }
+}
@@ -0,0 +1,267 @@
+use std::{borrow::Cow, io::Read, path::PathBuf, str::FromStr};
+
+use anyhow::{bail, Context};
+
+use crate::{answers, bench::BenchOptions, input, runner::Part, SolutionData};
+
+pub const USAGE: &str = "\
+Usage: day<N> [options] [a|b]
+
+Options:
+  --part <a|b|both>         Part to run, defaults to both
+  --input <path|->          Read the puzzle input from a file, or from stdin with `-`
+  --input-dir <dir>         Read the puzzle input from <dir>/day<N>/input.txt
+  --sample                  Run on the sample input instead of the puzzle input
+  --repeat <n>              Run the parts <n> times, reporting each run
+  --quiet                   Print only the answers in text output
+  --record                  Save the answers to answers.toml next to the input once confirmed
+  --submit                  Submit the answer of part a or b to the site, recording it when correct
+  --format <text|json|csv>  Output format, defaults to text
+  --bench                   Benchmark parse and the parts instead of reporting single runs
+  --warmup <n>              Untimed runs before benchmarking, defaults to 5
+  --iterations <n>          Timed benchmark runs, defaults to 100
+  --baseline <path>         Compare the benchmark against a baseline saved earlier
+  --save-baseline <path>    Save the benchmark as a baseline
+  -h, --help                Print this message";
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum InputSource {
+    /// `input.txt` from [`input::INPUT_DIR_VAR`] or the day's source directory.
+    #[default]
+    Default,
+    Dir(PathBuf),
+    File(PathBuf),
+    Stdin,
+    Sample,
+}
+
+impl InputSource {
+    pub fn load<T: SolutionData + ?Sized>(&self, part: Part) -> anyhow::Result<Cow<'static, str>> {
+        match self {
+            InputSource::Default => input::load::<T>(None),
+            InputSource::Dir(dir) => input::load::<T>(Some(dir)),
+            InputSource::File(path) => std::fs::read_to_string(path)
+                .map(Cow::Owned)
+                .with_context(|| format!("Could not read input {}", path.display())),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .context("Could not read input from stdin")?;
+                Ok(Cow::Owned(input))
+            }
+            InputSource::Sample if part == Part::Both && T::SAMPLE_INPUT_B.is_some() => {
+                bail!(
+                    "Day {} has a separate part b sample, pick a part to run",
+                    T::DAY
+                )
+            }
+            InputSource::Sample => Ok(Cow::Borrowed(T::sample_input(part))),
+        }
+    }
+
+    /// The `answers.toml` checked against, only the puzzle input has recorded answers.
+    pub fn answers_path<T: SolutionData + ?Sized>(&self) -> Option<PathBuf> {
+        match self {
+            InputSource::Default => Some(answers::path::<T>(None)),
+            InputSource::Dir(dir) => Some(answers::path::<T>(Some(dir))),
+            InputSource::File(_) | InputSource::Stdin | InputSource::Sample => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Format {
+    #[default]
+    Text,
+    /// One JSON record per part and run.
+    Json,
+    /// One CSV row per part and run, after a header.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => bail!("Unknown format `{s}`, expected text, json or csv"),
+        }
+    }
+}
+
+/// Command line of a single day binary.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Args {
+    pub part: Part,
+    pub input: InputSource,
+    pub repeat: usize,
+    pub quiet: bool,
+    pub record: bool,
+    pub submit: bool,
+    pub format: Format,
+    pub bench: bool,
+    pub bench_options: BenchOptions,
+    pub baseline: Option<PathBuf>,
+    pub save_baseline: Option<PathBuf>,
+    pub help: bool,
+}
+
+impl Default for Args {
+    fn default() -> Self {
+        Self {
+            part: Part::Both,
+            input: InputSource::Default,
+            repeat: 1,
+            quiet: false,
+            record: false,
+            submit: false,
+            format: Format::Text,
+            bench: false,
+            bench_options: BenchOptions::default(),
+            baseline: None,
+            save_baseline: None,
+            help: false,
+        }
+    }
+}
+
+impl Args {
+    pub fn from_env() -> anyhow::Result<Self> {
+        Self::parse(std::env::args().skip(1))
+    }
+
+    pub fn parse(args: impl IntoIterator<Item = String>) -> anyhow::Result<Self> {
+        let mut parsed = Self::default();
+        let mut args = args.into_iter();
+        let mut input_set = false;
+        while let Some(arg) = args.next() {
+            let mut value = |flag: &str| {
+                args.next()
+                    .with_context(|| format!("{flag} requires a value\n\n{USAGE}"))
+            };
+            let input = match arg.as_str() {
+                "a" | "b" => {
+                    parsed.part = arg.parse()?;
+                    None
+                }
+                "--part" => {
+                    parsed.part = value("--part")?.parse()?;
+                    None
+                }
+                "--input" => match value("--input")?.as_str() {
+                    "-" => Some(InputSource::Stdin),
+                    path => Some(InputSource::File(PathBuf::from(path))),
+                },
+                "--input-dir" => Some(InputSource::Dir(PathBuf::from(value("--input-dir")?))),
+                "--sample" => Some(InputSource::Sample),
+                "--repeat" => {
+                    parsed.repeat = count(&value("--repeat")?, 1)?;
+                    None
+                }
+                "--quiet" => {
+                    parsed.quiet = true;
+                    None
+                }
+                "--record" => {
+                    parsed.record = true;
+                    None
+                }
+                "--submit" => {
+                    parsed.submit = true;
+                    None
+                }
+                "--format" => {
+                    parsed.format = value("--format")?.parse()?;
+                    None
+                }
+                "--bench" => {
+                    parsed.bench = true;
+                    None
+                }
+                "--warmup" => {
+                    parsed.bench_options.warmup = count(&value("--warmup")?, 0)?;
+                    None
+                }
+                "--iterations" => {
+                    parsed.bench_options.iterations = count(&value("--iterations")?, 1)?;
+                    None
+                }
+                "--baseline" => {
+                    parsed.baseline = Some(PathBuf::from(value("--baseline")?));
+                    None
+                }
+                "--save-baseline" => {
+                    parsed.save_baseline = Some(PathBuf::from(value("--save-baseline")?));
+                    None
+                }
+                "-h" | "--help" => {
+                    parsed.help = true;
+                    None
+                }
+                _ => bail!("Unknown argument `{arg}`\n\n{USAGE}"),
+            };
+            if let Some(input) = input {
+                if input_set {
+                    bail!("Only one of --input, --input-dir and --sample may be given");
+                }
+                input_set = true;
+                parsed.input = input;
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+fn count(value: &str, min: usize) -> anyhow::Result<usize> {
+    value
+        .parse()
+        .ok()
+        .filter(|&count| count >= min)
+        .with_context(|| format!("Invalid count `{value}`, expected at least {min}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> anyhow::Result<Args> {
+        Args::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parses_every_option() -> anyhow::Result<()> {
+        let args = parse(&[
+            "--part", "b", "--input", "-", "--repeat", "3", "--quiet", "--format", "json",
+        ])?;
+        assert_eq!(
+            args,
+            Args {
+                part: Part::B,
+                input: InputSource::Stdin,
+                repeat: 3,
+                quiet: true,
+                format: Format::Json,
+                ..Args::default()
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn keeps_bare_part() -> anyhow::Result<()> {
+        assert_eq!(parse(&["a"])?.part, Part::A);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_bad_arguments() {
+        assert!(parse(&["c"]).is_err());
+        assert!(parse(&["--repeat", "0"]).is_err());
+        assert!(parse(&["--format"]).is_err());
+        assert!(parse(&["--sample", "--input", "input.txt"]).is_err());
+    }
+}
@@ -0,0 +1,433 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+};
+
+use super::{
+    extended::Diagonals,
+    unsigned::{Dimensions, Point},
+    Direction,
+};
+use crate::parse::{self, ParseError, ParseResult, Reason};
+
+/// A row that isn't as wide as the first, rows are counted from 0.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RaggedRow {
+    pub row: usize,
+    pub width: usize,
+    pub expected: usize,
+}
+
+impl Display for RaggedRow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "row {} is {} wide, expected {}",
+            self.row, self.width, self.expected
+        )
+    }
+}
+
+impl std::error::Error for RaggedRow {}
+
+/// What [`Grid::parse_with`] makes of a character.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Tile<T> {
+    Plain(T),
+    /// A cell whose position is also kept in the [`Markers`] under its character, e.g. a maze's start.
+    Marker(T),
+}
+
+/// Positions of the marker characters found by [`Grid::parse_with`], row by row.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Markers(HashMap<char, Vec<Point>>);
+
+impl Markers {
+    pub fn get(&self, marker: char) -> &[Point] {
+        self.0.get(&marker).map_or(&[], Vec::as_slice)
+    }
+
+    /// The only position of `marker` in `input`, the text the grid was parsed from.
+    pub fn single(&self, input: &str, marker: char, what: &'static str) -> ParseResult<Point> {
+        self.single_of(input, &[marker], what)
+            .map(|(_, point)| point)
+    }
+
+    /// The only position of any of `markers` in `input` and which marker it holds.
+    pub fn single_of(
+        &self,
+        input: &str,
+        markers: &[char],
+        what: &'static str,
+    ) -> ParseResult<(char, Point)> {
+        let mut found = markers
+            .iter()
+            .flat_map(|&marker| self.get(marker).iter().map(move |&point| (marker, point)))
+            .collect::<Vec<_>>();
+        found.sort_by_key(|(_, point)| (point.get().1, point.get().0));
+        match found[..] {
+            [] => Err(ParseError::missing(input, &input[input.len()..], what)),
+            [found] => Ok(found),
+            [_, (_, second), ..] => {
+                let (x, y) = second.get();
+                let text = parse::chars(input)
+                    .nth(y)
+                    .and_then(|(_, mut line)| line.nth(x))
+                    .map_or("", |(_, _, text)| text);
+                Err(ParseError::at(
+                    input,
+                    text,
+                    Reason::Unexpected("second marker"),
+                ))
+            }
+        }
+    }
+}
+
+/// Cells stored row by row in `S`, indexed by [`Point`] with `x` the column and `y` the row.
+///
+/// The storage is a `Vec` unless the grid is a view of the input from [`Grid::from_lines`], whose
+/// rows are `stride` bytes apart to step over the line breaks.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Grid<T, S = Vec<T>> {
+    cells: S,
+    dimensions: Dimensions,
+    stride: usize,
+    cell: PhantomData<T>,
+}
+
+impl<T> Grid<T> {
+    fn from_cells(cells: Vec<T>, dimensions: Dimensions) -> Self {
+        Self {
+            cells,
+            dimensions,
+            stride: dimensions.get_width(),
+            cell: PhantomData,
+        }
+    }
+
+    pub fn new(dimensions: Dimensions, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        let (width, len) = dimensions.get();
+        Self::from_cells(vec![fill; width * len], dimensions)
+    }
+
+    /// Fills each cell from its point.
+    pub fn from_fn(dimensions: Dimensions, mut cell: impl FnMut(Point) -> T) -> Self {
+        let (width, len) = dimensions.get();
+        let cells = (0..len)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x, y)))
+            .map(&mut cell)
+            .collect();
+        Self::from_cells(cells, dimensions)
+    }
+
+    /// Lays out `rows` top to bottom, they all have to be as wide as the first.
+    pub fn from_rows<R: IntoIterator<Item = T>>(
+        rows: impl IntoIterator<Item = R>,
+    ) -> Result<Self, RaggedRow> {
+        let mut cells = Vec::new();
+        let mut width = 0;
+        let mut len = 0;
+        for (row, cells_in_row) in rows.into_iter().enumerate() {
+            let start = cells.len();
+            cells.extend(cells_in_row);
+            let row_width = cells.len() - start;
+            if row == 0 {
+                width = row_width;
+            } else if row_width != width {
+                return Err(RaggedRow {
+                    row,
+                    width: row_width,
+                    expected: width,
+                });
+            }
+            len += 1;
+        }
+        Ok(Self::from_cells(cells, Dimensions::new(width, len)))
+    }
+
+    /// One cell per character of each line of `input`, `cell` returning `None` for characters the
+    /// puzzle doesn't use. Errors point at the first unknown character or row narrower or wider than
+    /// the first.
+    pub fn parse_with(
+        input: &str,
+        mut cell: impl FnMut(char, Point) -> Option<Tile<T>>,
+    ) -> ParseResult<(Self, Markers)> {
+        let mut cells = Vec::new();
+        let mut markers = Markers::default();
+        let mut width = 0;
+        let mut len = 0;
+        for (y, line) in parse::chars(input) {
+            let start = cells.len();
+            for (x, ch, text) in line {
+                let point = Point::new(x, y);
+                match cell(ch, point) {
+                    Some(Tile::Plain(value)) => cells.push(value),
+                    Some(Tile::Marker(value)) => {
+                        markers.0.entry(ch).or_default().push(point);
+                        cells.push(value);
+                    }
+                    None => return Err(ParseError::at(input, text, Reason::UnknownChar(ch))),
+                }
+            }
+            let row_width = cells.len() - start;
+            if y == 0 {
+                width = row_width;
+            } else if row_width != width {
+                return Err(RaggedRow {
+                    row: y,
+                    width: row_width,
+                    expected: width,
+                }
+                .into());
+            }
+            len += 1;
+        }
+        Ok((
+            Self::from_cells(cells, Dimensions::new(width, len)),
+            markers,
+        ))
+    }
+}
+
+impl<'a> Grid<u8, &'a [u8]> {
+    /// A view of the bytes of `input`'s lines without copying them. The lines all have to be as wide
+    /// as the first and end with the same line break.
+    pub fn from_lines(input: &'a str) -> Result<Self, RaggedRow> {
+        let offset = |line: &str| line.as_ptr() as usize - input.as_ptr() as usize;
+        let width = input.lines().next().map_or(0, str::len);
+        let stride = input.lines().nth(1).map_or(width + 1, offset);
+        let mut len = 0;
+        for (row, line) in input.lines().enumerate() {
+            if line.len() != width || offset(line) != row * stride {
+                return Err(RaggedRow {
+                    row,
+                    width: line.len(),
+                    expected: width,
+                });
+            }
+            len += 1;
+        }
+        Ok(Self {
+            cells: input.as_bytes(),
+            dimensions: Dimensions::new(width, len),
+            stride,
+            cell: PhantomData,
+        })
+    }
+}
+
+impl<T, S: AsRef<[T]>> Grid<T, S> {
+    pub fn dimensions(&self) -> Dimensions {
+        self.dimensions
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.offset(point)
+            .map(|offset| &self.cells.as_ref()[offset])
+    }
+
+    fn offset(&self, point: Point) -> Option<usize> {
+        let (x, y) = point.get();
+        self.dimensions
+            .is_within_bounds_exclusive(point)
+            .then(|| y * self.stride + x)
+    }
+
+    /// A grid of the same dimensions with `cell` applied to every cell.
+    pub fn map<U>(&self, cell: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::from_cells(self.rows().flatten().map(cell).collect(), self.dimensions)
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        let start = y * self.stride;
+        (y < self.dimensions.get_len())
+            .then(|| &self.cells.as_ref()[start..start + self.dimensions.get_width()])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.dimensions.get_len()).filter_map(|y| self.row(y))
+    }
+
+    /// Column `x` top to bottom, empty when `x` is out of bounds.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let (width, len) = self.dimensions.get();
+        (0..if x < width { len } else { 0 }).map(move |y| &self.cells.as_ref()[y * self.stride + x])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.dimensions.get_width()).map(|x| self.column(x))
+    }
+
+    /// Every cell with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.rows().enumerate().flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .map(move |(x, cell)| (Point::new(x, y), cell))
+        })
+    }
+
+    /// Points holding `value`, row by row.
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(point, _)| point)
+    }
+
+    /// The points up, right, down and left of `point` that are on the grid.
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::iter()
+            .map(move |direction| point + direction)
+            .filter(|&neighbor| self.dimensions.is_within_bounds_exclusive(neighbor))
+    }
+
+    /// [`neighbors4`](Self::neighbors4) and the diagonal neighbours.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Diagonals::iter()
+            .map(move |direction| point + direction.delta())
+            .filter(|&neighbor| self.dimensions.is_within_bounds_exclusive(neighbor))
+    }
+}
+
+impl<T, S: AsRef<[T]> + AsMut<[T]>> Grid<T, S> {
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.offset(point)
+            .map(|offset| &mut self.cells.as_mut()[offset])
+    }
+
+    /// Swaps the cells at `a` and `b`, panicking like indexing when either is out of bounds.
+    pub fn swap(&mut self, a: Point, b: Point) {
+        let offsets = self.offset(a).zip(self.offset(b));
+        let (a, b) = offsets
+            .unwrap_or_else(|| panic!("{a:?} or {b:?} is outside a {:?} grid", self.dimensions));
+        self.cells.as_mut().swap(a, b);
+    }
+}
+
+impl<T, S: AsRef<[T]>> Index<Point> for Grid<T, S> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside a {:?} grid", self.dimensions))
+    }
+}
+
+impl<T, S: AsRef<[T]> + AsMut<[T]>> IndexMut<Point> for Grid<T, S> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        let dimensions = self.dimensions;
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside a {dimensions:?} grid"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<u8> {
+        Grid::from_rows(["abc", "def"].map(str::bytes)).unwrap()
+    }
+
+    #[test]
+    fn indexes_by_column_then_row() {
+        let grid = grid();
+        assert_eq!(grid.dimensions(), Dimensions::new(3, 2));
+        assert_eq!(grid[Point::new(2, 0)], b'c');
+        assert_eq!(grid.get(Point::new(0, 1)), Some(&b'd'));
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.row(1), Some(&b"def"[..]));
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), b"be");
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(
+            grid.positions_of(&b'e').collect::<Vec<_>>(),
+            [Point::new(1, 1)]
+        );
+    }
+
+    #[test]
+    fn keeps_neighbors_in_bounds() {
+        let grid = grid();
+        let mut corner = grid.neighbors4(Point::new(0, 0)).collect::<Vec<_>>();
+        corner.sort_by_key(|point| point.get());
+        assert_eq!(corner, [Point::new(0, 1), Point::new(1, 0)]);
+        assert_eq!(grid.neighbors8(Point::new(1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn parses_markers() {
+        let input = "#S.\n.E#\n";
+        let parse = |input| {
+            Grid::parse_with(input, |ch, _| match ch {
+                '#' => Some(Tile::Plain(true)),
+                '.' => Some(Tile::Plain(false)),
+                'S' | 'E' => Some(Tile::Marker(false)),
+                _ => None,
+            })
+        };
+        let (grid, markers) = parse(input).unwrap();
+        assert_eq!(grid.positions_of(&true).count(), 2);
+        assert_eq!(markers.single(input, 'E', "an end"), Ok(Point::new(1, 1)));
+        assert_eq!(markers.get('x'), []);
+        let err = markers.single(input, 'x', "an x").unwrap_err();
+        assert_eq!(err.to_string(), "line 3, column 1: expected an x");
+        let input = "SS\n..\n";
+        let err = parse(input)
+            .unwrap()
+            .1
+            .single(input, 'S', "a start")
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 2: unexpected second marker `S`"
+        );
+        let err = parse("..\n.?.\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 2: unknown character `?`");
+        let err = parse("..\n...\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: row is 3 wide, expected 2"
+        );
+    }
+
+    #[test]
+    fn views_lines_in_place() {
+        let input = "abc\r\ndef\r\n";
+        let grid = Grid::from_lines(input).unwrap();
+        assert_eq!(grid.dimensions(), Dimensions::new(3, 2));
+        assert_eq!(grid[Point::new(0, 1)], b'd');
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [b"abc", b"def"]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), b"cf");
+        assert_eq!(grid.map(|&cell| cell), self::grid());
+        assert_eq!(
+            Grid::from_lines("abc\nde\n"),
+            Err(RaggedRow {
+                row: 1,
+                width: 2,
+                expected: 3
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        assert_eq!(
+            Grid::from_rows(["ab", "abc"].map(str::bytes)),
+            Err(RaggedRow {
+                row: 1,
+                width: 3,
+                expected: 2
+            })
+        );
+    }
+}
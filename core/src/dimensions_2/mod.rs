@@ -1,6 +1,8 @@
 use std::array::IntoIter;
 
-// pub mod signed;
+pub mod grid;
+pub mod render;
+pub mod signed;
 pub mod unsigned;
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -102,9 +104,7 @@ pub mod extended {
         }
 
         pub fn iter() -> IntoIter<Diagonals> {
-            let mut vec = Direction::iter()
-                .map(|direction| Diagonals::Cardinal(direction))
-                .collect_vec();
+            let mut vec = Direction::iter().map(Diagonals::Cardinal).collect_vec();
             vec.push(Diagonals::UpLeft);
             vec.push(Diagonals::UpRight);
             vec.push(Diagonals::DownLeft);
@@ -0,0 +1,124 @@
+use std::{collections::HashMap, fmt::Display};
+
+use super::{grid::Grid, unsigned::Point};
+
+/// The character a cell is drawn as when its grid is rendered.
+pub trait Render {
+    fn render(&self) -> char;
+}
+
+impl Render for char {
+    fn render(&self) -> char {
+        *self
+    }
+}
+
+impl Render for u8 {
+    fn render(&self) -> char {
+        *self as char
+    }
+}
+
+/// `#` for true, `.` for false, as the puzzles draw walls and open tiles.
+impl Render for bool {
+    fn render(&self) -> char {
+        if *self {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+/// Empty cells are drawn as `.`.
+impl<T: Render> Render for Option<T> {
+    fn render(&self) -> char {
+        self.as_ref().map_or('.', Render::render)
+    }
+}
+
+/// The ANSI foreground colours a [`Rendered`] grid can highlight points in.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Colour {
+    fn code(self) -> u8 {
+        match self {
+            Colour::Red => 31,
+            Colour::Green => 32,
+            Colour::Yellow => 33,
+            Colour::Blue => 34,
+            Colour::Magenta => 35,
+            Colour::Cyan => 36,
+        }
+    }
+}
+
+/// A grid drawn one line per row through [`Display`], so it can be printed or kept with `to_string`.
+#[derive(Clone, Debug)]
+pub struct Rendered<'a, T, S = Vec<T>> {
+    grid: &'a Grid<T, S>,
+    highlights: HashMap<Point, Colour>,
+}
+
+impl<T, S> Rendered<'_, T, S> {
+    /// Draws `points` in `colour`, over any earlier highlight of the same point.
+    pub fn highlight(mut self, points: impl IntoIterator<Item = Point>, colour: Colour) -> Self {
+        self.highlights
+            .extend(points.into_iter().map(|point| (point, colour)));
+        self
+    }
+}
+
+impl<T: Render, S: AsRef<[T]>> Display for Rendered<'_, T, S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.grid.rows().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                let ch = cell.render();
+                match self.highlights.get(&Point::new(x, y)) {
+                    Some(colour) => write!(f, "\x1b[{}m{ch}\x1b[0m", colour.code())?,
+                    None => write!(f, "{ch}")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Render, S: AsRef<[T]>> Grid<T, S> {
+    pub fn render(&self) -> Rendered<'_, T, S> {
+        Rendered {
+            grid: self,
+            highlights: HashMap::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_rows_and_highlights() {
+        let grid = Grid::from_rows(["#.", ".#"].map(str::chars)).unwrap();
+        assert_eq!(grid.render().to_string(), "#.\n.#\n");
+        let highlighted = grid
+            .render()
+            .highlight([Point::new(1, 0), Point::new(0, 1)], Colour::Red)
+            .highlight([Point::new(0, 1)], Colour::Green);
+        assert_eq!(
+            highlighted.to_string(),
+            "#\x1b[31m.\x1b[0m\n\x1b[32m.\x1b[0m#\n"
+        );
+        let open = grid.map(|&ch| ch == '.');
+        assert_eq!(open.render().to_string(), ".#\n#.\n");
+    }
+}
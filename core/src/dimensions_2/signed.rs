@@ -0,0 +1,209 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use super::{
+    unsigned::{self, Dimensions},
+    Direction,
+};
+
+/// A position that can step past the top or left edge without wrapping.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Point {
+    x: isize,
+    y: isize,
+}
+
+impl Point {
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn get(&self) -> (isize, isize) {
+        (self.x, self.y)
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> usize {
+        (self - other).manhattan_len()
+    }
+
+    pub fn chebyshev_distance(self, other: Point) -> usize {
+        (self - other).chebyshev_len()
+    }
+
+    /// `point` if it's within `dimensions`.
+    pub fn from_unsigned(point: unsigned::Point, dimensions: Dimensions) -> Option<Self> {
+        let (x, y) = point.get();
+        if !dimensions.is_within_bounds_exclusive(point) {
+            return None;
+        }
+        Some(Self::new(x.try_into().ok()?, y.try_into().ok()?))
+    }
+
+    /// The unsigned point if it's within `dimensions`.
+    pub fn to_unsigned(self, dimensions: Dimensions) -> Option<unsigned::Point> {
+        let point = unsigned::Point::new(self.x.try_into().ok()?, self.y.try_into().ok()?);
+        dimensions
+            .is_within_bounds_exclusive(point)
+            .then_some(point)
+    }
+}
+
+/// The offset between two points.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Vector {
+    x: isize,
+    y: isize,
+}
+
+impl Vector {
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn get(&self) -> (isize, isize) {
+        (self.x, self.y)
+    }
+
+    pub fn manhattan_len(self) -> usize {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    pub fn chebyshev_len(self) -> usize {
+        self.x.unsigned_abs().max(self.y.unsigned_abs())
+    }
+}
+
+impl From<(isize, isize)> for Vector {
+    fn from((x, y): (isize, isize)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<Direction> for Vector {
+    fn from(direction: Direction) -> Self {
+        direction.delta().into()
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Vector) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Direction) -> Self::Output {
+        self + Vector::from(rhs)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, rhs: Vector) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Self;
+
+    fn sub(self, rhs: Vector) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, rhs: Vector) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sub<Point> for Point {
+    type Output = Vector;
+
+    fn sub(self, rhs: Point) -> Self::Output {
+        Vector::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Add<Vector> for Vector {
+    type Output = Self;
+
+    fn add(self, rhs: Vector) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign<Vector> for Vector {
+    fn add_assign(&mut self, rhs: Vector) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Vector> for Vector {
+    type Output = Self;
+
+    fn sub(self, rhs: Vector) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl SubAssign<Vector> for Vector {
+    fn sub_assign(&mut self, rhs: Vector) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Vector {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steps_past_the_edge() {
+        let start = Point::new(0, 2);
+        let left = start + Direction::Left;
+        assert_eq!(left, Point::new(-1, 2));
+        assert_eq!(left - start, Vector::new(-1, 0));
+        assert_eq!(start + Vector::new(3, -1) * 2, Point::new(6, 0));
+        assert_eq!(start - -Vector::new(1, 1), Point::new(1, 3));
+        assert_eq!(left.manhattan_distance(Point::new(2, -2)), 7);
+        assert_eq!(left.chebyshev_distance(Point::new(2, -2)), 4);
+    }
+
+    #[test]
+    fn converts_within_dimensions() {
+        let dimensions = Dimensions::new(3, 2);
+        assert_eq!(
+            Point::new(2, 1).to_unsigned(dimensions),
+            Some(unsigned::Point::new(2, 1))
+        );
+        assert_eq!(Point::new(-1, 1).to_unsigned(dimensions), None);
+        assert_eq!(Point::new(3, 1).to_unsigned(dimensions), None);
+        assert_eq!(
+            Point::from_unsigned(unsigned::Point::new(1, 1), dimensions),
+            Some(Point::new(1, 1))
+        );
+        assert_eq!(
+            Point::from_unsigned(unsigned::Point::new(1, 2), dimensions),
+            None
+        );
+    }
+}
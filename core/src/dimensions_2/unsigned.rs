@@ -0,0 +1,230 @@
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+use super::{signed::Vector, Direction};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Point {
+    x: usize,
+    y: usize,
+}
+
+impl Point {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn get(&self) -> (usize, usize) {
+        (self.x, self.y)
+    }
+
+    /// `self + vector` unless it would go past the top or left edge, or outside `dimensions` if given.
+    pub fn checked_add(self, vector: Vector, dimensions: Option<Dimensions>) -> Option<Point> {
+        let (dx, dy) = vector.get();
+        let point = Point::new(
+            self.x.checked_add_signed(dx)?,
+            self.y.checked_add_signed(dy)?,
+        );
+        dimensions
+            .is_none_or(|dimensions| dimensions.is_within_bounds_exclusive(point))
+            .then_some(point)
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+/// The offset from the origin.
+impl From<Point> for Vector {
+    fn from(point: Point) -> Self {
+        Vector::new(point.x as isize, point.y as isize)
+    }
+}
+
+impl Add<Point> for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Point) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl Add<(isize, isize)> for Point {
+    type Output = Self;
+
+    fn add(self, rhs: (isize, isize)) -> Self::Output {
+        Self {
+            x: self.x.wrapping_add_signed(rhs.0),
+            y: self.y.wrapping_add_signed(rhs.1),
+        }
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Direction) -> Self::Output {
+        self + rhs.delta()
+    }
+}
+
+impl AddAssign<(isize, isize)> for Point {
+    fn add_assign(&mut self, rhs: (isize, isize)) {
+        self.x = self.x.wrapping_add_signed(rhs.0);
+        self.y = self.y.wrapping_add_signed(rhs.1);
+    }
+}
+
+/// Panics past the top or left edge, use [`Point::checked_add`] when that can happen.
+impl Add<Vector> for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Vector) -> Self::Output {
+        self.checked_add(rhs, None)
+            .unwrap_or_else(|| panic!("{self:?} + {rhs:?} is past the top or left edge"))
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, rhs: Vector) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Self;
+
+    fn sub(self, rhs: Vector) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, rhs: Vector) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sub<Point> for Point {
+    type Output = Vector;
+
+    fn sub(self, rhs: Point) -> Self::Output {
+        Vector::from(self) - Vector::from(rhs)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Dimensions {
+    width: usize,
+    len: usize,
+}
+
+impl Dimensions {
+    pub const fn new(width: usize, len: usize) -> Self {
+        Self { width, len }
+    }
+
+    pub fn get(&self) -> (usize, usize) {
+        (self.width, self.len)
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get_len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_within_bounds_exclusive(&self, point: Point) -> bool {
+        let (x, y) = point.get();
+        self.len > y && self.width > x
+    }
+
+    pub fn is_within_bounds_inclusive(&self, point: Point) -> bool {
+        let (x, y) = point.get();
+        self.len >= y && self.width >= x
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Line {
+    start: Point,
+    end: Point,
+}
+
+impl Line {
+    pub fn new(start: Point, end: Point) -> Self {
+        Self { start, end }
+    }
+
+    pub fn get_start(self) -> Point {
+        self.start
+    }
+
+    pub fn get_end(self) -> Point {
+        self.end
+    }
+
+    pub fn slope(self) -> isize {
+        (self.end.get().1 as isize - self.start.get().1 as isize)
+            / (self.end.get().0 as isize - self.start.get().0 as isize)
+    }
+
+    pub fn contains_point(&self, point: &Point) -> bool {
+        let (dx1, dy1) = (
+            self.end.get().0 as isize - self.start.get().0 as isize,
+            self.end.get().1 as isize - self.start.get().1 as isize,
+        );
+        let (dx2, dy2) = (
+            point.get().0 as isize - self.start.get().0 as isize,
+            point.get().1 as isize - self.start.get().1 as isize,
+        );
+
+        let cross_product = dy1 * dx2 - dx1 * dy2;
+
+        cross_product == 0
+    }
+
+    /// Whether both ends of `other` are on the infinite line through self, equality compares the
+    /// endpoints.
+    pub fn is_co_linear(&self, other: &Self) -> bool {
+        self.contains_point(&other.start) && self.contains_point(&other.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "past the top or left edge")]
+    fn panics_past_the_edge() {
+        let _ = Point::new(0, 3) + Vector::new(-1, 0);
+    }
+
+    #[test]
+    fn steps_by_vectors() {
+        let (start, end) = (Point::new(4, 3), Point::new(5, 5));
+        let delta = end - start;
+        assert_eq!(delta, Vector::new(1, 2));
+        assert_eq!(start - delta, Point::new(3, 1));
+        assert_eq!(end.checked_add(delta, None), Some(Point::new(6, 7)));
+        assert_eq!(end.checked_add(delta, Some(Dimensions::new(7, 7))), None);
+        assert_eq!(start.checked_add(delta * -2, None), None);
+    }
+
+    #[test]
+    fn lines_compare_endpoints() {
+        let line = Line::new(Point::new(0, 0), Point::new(1, 1));
+        let further = Line::new(Point::new(2, 2), Point::new(3, 3));
+        assert_ne!(line, further);
+        assert!(line.is_co_linear(&further));
+        assert!(!line.is_co_linear(&Line::new(Point::new(0, 1), Point::new(1, 1))));
+    }
+}
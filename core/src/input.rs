@@ -0,0 +1,81 @@
+use std::{
+    borrow::Cow,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+
+use crate::{cache::Cache, SolutionData};
+
+/// Environment variable pointing at a directory of puzzle inputs laid out as `day<N>/input.txt`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Resolves where the puzzle input for `T` lives.
+///
+/// An explicit `input_dir` wins over [`INPUT_DIR_VAR`], and both fall back to the crate's `src/bin`
+/// when it holds `day<N>/input.txt`, then to the year's directory in the [`Cache`].
+pub fn input_path<T: SolutionData + ?Sized>(input_dir: Option<&Path>) -> PathBuf {
+    let day = format!("day{}", T::DAY);
+    let input_dir = match input_dir {
+        Some(dir) => dir.to_path_buf(),
+        None => match std::env::var_os(INPUT_DIR_VAR) {
+            Some(dir) => PathBuf::from(dir),
+            None => {
+                let src_bin = Path::new(T::MANIFEST_DIR).join("src").join("bin");
+                match Cache::from_env() {
+                    Ok(cache) if !src_bin.join(&day).join("input.txt").is_file() => {
+                        cache.year_dir(T::YEAR)
+                    }
+                    _ => src_bin,
+                }
+            }
+        },
+    };
+    input_dir.join(day).join("input.txt")
+}
+
+/// Loads the puzzle input for `T`, preferring the embedded copy when one was compiled in unless
+/// `input_dir` asks for another directory.
+pub fn load<T: SolutionData + ?Sized>(
+    input_dir: Option<&Path>,
+) -> anyhow::Result<Cow<'static, str>> {
+    load_optional::<T>(input_dir)?.with_context(|| {
+        format!(
+            "No input for day {} at {}, fetch it with `cargo run --bin aoc -- fetch {} {}` or set {}",
+            T::DAY,
+            input_path::<T>(input_dir).display(),
+            T::YEAR,
+            T::DAY,
+            INPUT_DIR_VAR,
+        )
+    })
+}
+
+/// Like [`load`], but a missing input file is `Ok(None)` rather than an error.
+pub fn load_optional<T: SolutionData + ?Sized>(
+    input_dir: Option<&Path>,
+) -> anyhow::Result<Option<Cow<'static, str>>> {
+    if let (None, Some(input)) = (input_dir, T::EMBEDDED_INPUT) {
+        return Ok(Some(Cow::Borrowed(input)));
+    }
+    let path = input_path::<T>(input_dir);
+    match std::fs::read_to_string(&path) {
+        Ok(input) => Ok(Some(Cow::Owned(input))),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err).with_context(|| format!("Could not read input {}", path.display())),
+    }
+}
+
+/// Collects the digits of a name such as `Day16` or `aoc-2024` into a number.
+pub const fn number_in(name: &str) -> u16 {
+    let bytes = name.as_bytes();
+    let mut number = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i].is_ascii_digit() {
+            number = number * 10 + (bytes[i] - b'0') as u16;
+        }
+        i += 1;
+    }
+    number
+}
@@ -0,0 +1,468 @@
+//! The shared `Solution` framework, runner plumbing and geometry the year crates build on.
+
+use std::{
+    fmt::{Debug, Display},
+    path::Path,
+    time::Duration,
+};
+
+use anyhow::Context;
+
+use answers::Answers;
+use bench::{BenchOptions, BenchReport, Stats};
+use cache::{Cache, Fetcher};
+use cli::{Format, InputSource};
+use record::{RecordWriter, RunRecord};
+use runner::{DayRun, Part, PartRun};
+use submit::Verdict;
+
+pub mod answers;
+pub mod bench;
+pub mod build;
+pub mod cache;
+pub mod cli;
+pub mod dimensions_2;
+pub mod input;
+pub mod page;
+pub mod parse;
+pub mod record;
+pub mod runner;
+pub mod scaffold;
+#[cfg(test)]
+mod stand_in;
+pub mod submit;
+
+#[doc(hidden)]
+pub use inventory;
+pub use parse::{ParseError, ParseResult};
+
+pub trait SolutionData {
+    const YEAR: u16;
+    const DAY: u8;
+    /// Manifest directory of the crate holding the day, its `src/bin/day<N>` is the default home of
+    /// `input.txt`.
+    const MANIFEST_DIR: &'static str;
+    /// Puzzle input compiled into the binary, only set with the `embed-inputs` feature.
+    const EMBEDDED_INPUT: Option<&'static str> = None;
+    const SAMPLE_INPUT: &'static str;
+    /// Part b's own example when the puzzle publishes one, picked up from `sample_b.txt`.
+    const SAMPLE_INPUT_B: Option<&'static str> = None;
+
+    fn sample_input(part: Part) -> &'static str {
+        match (part, Self::SAMPLE_INPUT_B) {
+            (Part::B, Some(sample_b)) => sample_b,
+            _ => Self::SAMPLE_INPUT,
+        }
+    }
+}
+
+/// An extra example beyond `sample.txt`, e.g. a smaller or trickier grid from the puzzle text.
+#[derive(Clone, Debug)]
+pub struct Example<A: 'static, B: 'static = A> {
+    pub name: &'static str,
+    pub input: &'static str,
+    pub answer_a: Option<A>,
+    pub answer_b: Option<B>,
+}
+
+pub trait Solution<T: SolutionData> {
+    /// The parsed puzzle, free to borrow from the input text, e.g. a grid's rows as byte slices.
+    type Parsed<'input>: Debug;
+    type AnswerA: Debug + Display + PartialEq + 'static;
+    type AnswerB: Debug + Display + PartialEq + 'static;
+    /// Puzzle constants that differ between the sample and the real input, e.g. a grid size.
+    type Config: Debug + 'static;
+    const CONFIG: Self::Config;
+    /// Used for `sample.txt`, `sample_b.txt` and the examples.
+    const SAMPLE_CONFIG: Self::Config;
+    const SAMPLE_ANSWER_A: Self::AnswerA;
+    const SAMPLE_ANSWER_B: Self::AnswerB;
+    /// Examples tested alongside the sample, each reported by its name when it fails.
+    const EXAMPLES: &'static [Example<Self::AnswerA, Self::AnswerB>] = &[];
+
+    /// Reports malformed input as a [`ParseError`] pointing at the offending
+    /// text, see the helpers in [`parse`].
+    fn parse(input: &str) -> ParseResult<Self::Parsed<'_>>;
+    fn part_a(input: &Self::Parsed<'_>, config: &Self::Config) -> anyhow::Result<Self::AnswerA>;
+    fn part_b(input: &Self::Parsed<'_>, config: &Self::Config) -> anyhow::Result<Self::AnswerB>;
+
+    /// Solves both parts in one pass for days where part b builds on part a's work, e.g. a search
+    /// both parts read from. `None`, the default, runs the parts separately.
+    fn solve_both(
+        _input: &Self::Parsed<'_>,
+        _config: &Self::Config,
+    ) -> Option<anyhow::Result<(Self::AnswerA, Self::AnswerB)>> {
+        None
+    }
+
+    fn test_part_a() -> anyhow::Result<()> {
+        assert_eq!(
+            Self::part_a(
+                &Self::parse(T::sample_input(Part::A))?,
+                &Self::SAMPLE_CONFIG
+            )?,
+            Self::SAMPLE_ANSWER_A
+        );
+        if let Some(expected) = Answers::load(&answers::sample_path::<T>())?.a {
+            assert_eq!(
+                Self::SAMPLE_ANSWER_A.to_string(),
+                expected,
+                "SAMPLE_ANSWER_A differs from sample_answers.toml"
+            );
+        }
+        match input::load_optional::<T>(None)? {
+            Some(input) => {
+                let answer = Self::part_a(&Self::parse(&input)?, &Self::CONFIG)?;
+                if let Some(expected) = Answers::load(&answers::path::<T>(None))?.a {
+                    assert_eq!(
+                        answer.to_string(),
+                        expected,
+                        "part a differs from answers.toml"
+                    );
+                }
+                println!("a: {answer}");
+            }
+            None => println!("a: skipped, no input for day {}", T::DAY),
+        }
+        Ok(())
+    }
+
+    fn test_part_b() -> anyhow::Result<()> {
+        assert_eq!(
+            Self::part_b(
+                &Self::parse(T::sample_input(Part::B))?,
+                &Self::SAMPLE_CONFIG
+            )?,
+            Self::SAMPLE_ANSWER_B
+        );
+        if let Some(expected) = Answers::load(&answers::sample_path::<T>())?.b {
+            assert_eq!(
+                Self::SAMPLE_ANSWER_B.to_string(),
+                expected,
+                "SAMPLE_ANSWER_B differs from sample_answers.toml"
+            );
+        }
+        match input::load_optional::<T>(None)? {
+            Some(input) => {
+                let answer = Self::part_b(&Self::parse(&input)?, &Self::CONFIG)?;
+                if let Some(expected) = Answers::load(&answers::path::<T>(None))?.b {
+                    assert_eq!(
+                        answer.to_string(),
+                        expected,
+                        "part b differs from answers.toml"
+                    );
+                }
+                println!("b: {answer}");
+            }
+            None => println!("b: skipped, no input for day {}", T::DAY),
+        }
+        Ok(())
+    }
+
+    fn test_both() -> anyhow::Result<()> {
+        if T::SAMPLE_INPUT_B.is_some() {
+            return Ok(());
+        }
+        if let Some(answers) =
+            Self::solve_both(&Self::parse(T::SAMPLE_INPUT)?, &Self::SAMPLE_CONFIG)
+        {
+            assert_eq!(answers?, (Self::SAMPLE_ANSWER_A, Self::SAMPLE_ANSWER_B));
+        }
+        Ok(())
+    }
+
+    fn test_examples() -> anyhow::Result<()> {
+        for Example {
+            name,
+            input,
+            answer_a,
+            answer_b,
+        } in Self::EXAMPLES
+        {
+            let parsed = Self::parse(input).with_context(|| format!("example `{name}`"))?;
+            if let Some(answer) = answer_a {
+                let found = Self::part_a(&parsed, &Self::SAMPLE_CONFIG)
+                    .with_context(|| format!("part a of example `{name}`"))?;
+                assert_eq!(&found, answer, "part a of example `{name}`");
+            }
+            if let Some(answer) = answer_b {
+                let found = Self::part_b(&parsed, &Self::SAMPLE_CONFIG)
+                    .with_context(|| format!("part b of example `{name}`"))?;
+                assert_eq!(&found, answer, "part b of example `{name}`");
+            }
+        }
+        Ok(())
+    }
+
+    fn main() -> anyhow::Result<()> {
+        let args = cli::Args::from_env()?;
+        if args.help {
+            println!("{}", cli::USAGE);
+            return Ok(());
+        }
+        let answers_path = args.input.answers_path::<T>();
+        if args.record && answers_path.is_none() {
+            anyhow::bail!("--record needs the puzzle input, not --input or --sample");
+        }
+        if args.submit && (answers_path.is_none() || args.part == Part::Both) {
+            anyhow::bail!("--submit needs the puzzle input and one part, a or b");
+        }
+        let mut answers = answers_path
+            .as_deref()
+            .map(Answers::load)
+            .transpose()?
+            .unwrap_or_default();
+        let input = args.input.load::<T>(args.part)?;
+        let config = match args.input {
+            InputSource::Sample => &Self::SAMPLE_CONFIG,
+            _ => &Self::CONFIG,
+        };
+        if args.bench {
+            if args.format == Format::Csv {
+                anyhow::bail!("Benchmarks are reported as text or json");
+            }
+            let report = Self::bench(&input, args.part, config, args.bench_options)?;
+            let baseline = args
+                .baseline
+                .as_deref()
+                .map(BenchReport::load)
+                .transpose()?;
+            match args.format {
+                Format::Text => println!("{}", report.render(baseline.as_ref())),
+                Format::Json | Format::Csv => println!("{}", serde_json::to_string(&report)?),
+            }
+            if let Some(path) = &args.save_baseline {
+                report.save(path)?;
+            }
+            return Ok(());
+        }
+        let mut records = RecordWriter::new(args.format);
+        let mut last = None;
+        for _ in 0..args.repeat {
+            let mut run = match Self::run(&input, args.part, config) {
+                Ok(run) => run,
+                Err(err) if args.format != Format::Text => {
+                    records.write(&RunRecord::failed(T::YEAR, T::DAY, args.part, &err))?;
+                    return Err(err);
+                }
+                Err(err) => return Err(err),
+            };
+            answers.verify(&mut run);
+            match args.format {
+                Format::Text if !args.quiet => {
+                    println!("Parse took {:?}", run.parse);
+                    if run.solved_together {
+                        let a = run.a.as_ref().map(|a| a.elapsed).unwrap_or_default();
+                        println!("Parts a and b took {a:?}");
+                    } else {
+                        if let Some(a) = &run.a {
+                            println!("Part a took {:?}", a.elapsed);
+                        }
+                        if let Some(b) = &run.b {
+                            println!("Part b took {:?}", b.elapsed);
+                        }
+                    }
+                }
+                Format::Text => (),
+                Format::Json | Format::Csv => records.write(&RunRecord::from_run(&run))?,
+            }
+            last = Some(run);
+        }
+        let Some(run) = last else {
+            return Ok(());
+        };
+        if args.format == Format::Text {
+            if let Some(a) = &run.a {
+                println!("a: {a}");
+            }
+            if let Some(b) = &run.b {
+                println!("b: {b}");
+            }
+        }
+        if !run.is_success() {
+            anyhow::bail!("Day {} failed", T::DAY);
+        }
+        let mut record = args.record;
+        if args.submit {
+            let Some(Ok(answer)) = run.a.as_ref().or(run.b.as_ref()).map(|part| &part.answer)
+            else {
+                anyhow::bail!("No answer to submit");
+            };
+            let history = Cache::from_env()?.history_path(T::YEAR, T::DAY);
+            let reply = submit::submit(
+                &Fetcher::from_env()?,
+                &history,
+                T::YEAR,
+                T::DAY,
+                args.part,
+                answer,
+            )?;
+            println!("{}", reply.message);
+            record |= reply.verdict == Verdict::Correct;
+        }
+        if let (true, Some(path)) = (record, &answers_path) {
+            answers.record(&run)?;
+            answers.save(path)?;
+            eprintln!("Recorded answers in {}", path.display());
+        }
+        Ok(())
+    }
+
+    fn run(input: &str, part: Part, config: &Self::Config) -> anyhow::Result<DayRun> {
+        let (parsed, parse) = runner::timed(|| Self::parse(input));
+        let parsed = parsed?;
+        if part == Part::Both {
+            if let (Some(answers), elapsed) = runner::timed(|| Self::solve_both(&parsed, config)) {
+                let (a, b) = match answers {
+                    Ok((a, b)) => (Ok(a.to_string()), Ok(b.to_string())),
+                    Err(err) => (Err(format!("{err:#}")), Err(format!("{err:#}"))),
+                };
+                return Ok(DayRun {
+                    year: T::YEAR,
+                    day: T::DAY,
+                    parse,
+                    a: Some(PartRun { answer: a, elapsed }),
+                    b: Some(PartRun {
+                        answer: b,
+                        elapsed: Duration::ZERO,
+                    }),
+                    solved_together: true,
+                });
+            }
+        }
+        let a = part
+            .includes_a()
+            .then(|| PartRun::timed(|| Self::part_a(&parsed, config)));
+        let b = part
+            .includes_b()
+            .then(|| PartRun::timed(|| Self::part_b(&parsed, config)));
+        Ok(DayRun {
+            year: T::YEAR,
+            day: T::DAY,
+            parse,
+            a,
+            b,
+            solved_together: false,
+        })
+    }
+
+    fn bench(
+        input: &str,
+        part: Part,
+        config: &Self::Config,
+        options: BenchOptions,
+    ) -> anyhow::Result<BenchReport> {
+        for _ in 0..options.warmup {
+            Self::run(input, part, config)?;
+        }
+        let mut parse = Vec::with_capacity(options.iterations);
+        let mut a = Vec::with_capacity(options.iterations);
+        let mut b = Vec::with_capacity(options.iterations);
+        let mut solved_together = false;
+        for _ in 0..options.iterations {
+            let (parsed, elapsed) = runner::timed(|| Self::parse(input));
+            let parsed = parsed?;
+            parse.push(elapsed);
+            // Time what `run` times, one pass for both parts when the day has one.
+            if part == Part::Both {
+                if let (Some(answers), elapsed) =
+                    runner::timed(|| Self::solve_both(&parsed, config))
+                {
+                    answers?;
+                    a.push(elapsed);
+                    solved_together = true;
+                    continue;
+                }
+            }
+            if part.includes_a() {
+                let (answer, elapsed) = runner::timed(|| Self::part_a(&parsed, config));
+                answer?;
+                a.push(elapsed);
+            }
+            if part.includes_b() {
+                let (answer, elapsed) = runner::timed(|| Self::part_b(&parsed, config));
+                answer?;
+                b.push(elapsed);
+            }
+        }
+        Ok(BenchReport {
+            year: T::YEAR,
+            day: T::DAY,
+            iterations: options.iterations,
+            parse: Stats::from_samples(&mut parse),
+            a: part.includes_a().then(|| Stats::from_samples(&mut a)),
+            b: (part.includes_b() && !solved_together).then(|| Stats::from_samples(&mut b)),
+            solved_together,
+        })
+    }
+
+    fn load_and_run(input_dir: Option<&Path>, part: Part) -> anyhow::Result<DayRun> {
+        let mut run = Self::run(&input::load::<T>(input_dir)?, part, &Self::CONFIG)?;
+        Answers::load(&answers::path::<T>(input_dir))?.verify(&mut run);
+        Ok(run)
+    }
+}
+
+/// Declares a day, its sample tests and its examples. The crate's `build.rs` has to call
+/// [`build::generate`] for the `sample_b.txt` lookup.
+#[macro_export]
+macro_rules! aoc {
+    ($day:ident) => {
+        struct $day;
+
+        impl SolutionData for $day {
+            const YEAR: u16 = $crate::input::number_in(env!("CARGO_PKG_NAME"));
+            const DAY: u8 = $crate::input::number_in(stringify!($day)) as u8;
+            const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+            #[cfg(feature = "embed-inputs")]
+            const EMBEDDED_INPUT: Option<&'static str> = Some(include_str!("input.txt"));
+            const SAMPLE_INPUT: &'static str = include_str!("sample.txt");
+            const SAMPLE_INPUT_B: Option<&'static str> = include!(concat!(
+                env!("OUT_DIR"),
+                "/sample_b/",
+                stringify!($day),
+                ".rs"
+            ));
+        }
+
+        // Named apart from `tests` so a day can keep its own tests module.
+        #[cfg(test)]
+        mod solution {
+            use super::*;
+
+            #[test]
+            fn a() -> anyhow::Result<()> {
+                $day::test_part_a()
+            }
+
+            #[test]
+            fn b() -> anyhow::Result<()> {
+                $day::test_part_b()
+            }
+
+            #[test]
+            fn both() -> anyhow::Result<()> {
+                $day::test_both()
+            }
+
+            #[test]
+            fn examples() -> anyhow::Result<()> {
+                $day::test_examples()
+            }
+        }
+
+        $crate::inventory::submit! {
+            $crate::runner::RegisteredDay {
+                year: <$day as SolutionData>::YEAR,
+                day: <$day as SolutionData>::DAY,
+                run: <$day as Solution<$day>>::load_and_run,
+            }
+        }
+
+        // Unused when the day is compiled into its year's library as a module.
+        #[allow(dead_code)]
+        fn main() -> anyhow::Result<()> {
+            $day::main()
+        }
+    };
+}
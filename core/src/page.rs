@@ -0,0 +1,185 @@
+use anyhow::Context;
+
+use crate::answers::Answers;
+
+/// Text of every `<pre><code>` block on a puzzle page, in page order, with markup such as `<em>`
+/// removed and entities decoded.
+pub fn code_blocks(page: &str) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut rest = page;
+    while let Some((_, after)) = rest.split_once("<pre><code>") {
+        let Some((block, after)) = after.split_once("</code></pre>") else {
+            break;
+        };
+        blocks.push(text(block));
+        rest = after;
+    }
+    blocks
+}
+
+/// The chosen code blocks as one sample, separated by a blank line.
+pub fn sample_from(blocks: &[String], indices: &[usize]) -> anyhow::Result<String> {
+    let mut chosen = Vec::with_capacity(indices.len());
+    for &index in indices {
+        let block = blocks.get(index).with_context(|| {
+            format!(
+                "No code block {index}, the page has {} blocks",
+                blocks.len()
+            )
+        })?;
+        chosen.push(block.trim_end_matches('\n'));
+    }
+    Ok(chosen.join("\n\n") + "\n")
+}
+
+/// Highlighted values, `<code><em>…</em></code>`, of each part's article in page order. The
+/// example's answer is one of them, usually the last.
+pub fn answer_candidates(page: &str) -> Vec<Vec<String>> {
+    page.split(r#"<article class="day-desc">"#)
+        .skip(1)
+        .map(|article| {
+            let article = article.split("</article>").next().unwrap_or_default();
+            let mut candidates = Vec::new();
+            let mut rest = article;
+            while let Some((_, after)) = rest.split_once("<code><em>") {
+                let Some((candidate, after)) = after.split_once("</em></code>") else {
+                    break;
+                };
+                candidates.push(text(candidate));
+                rest = after;
+            }
+            candidates
+        })
+        .collect()
+}
+
+/// Takes the last candidate of each part as its sample answer.
+pub fn proposed_sample_answers(page: &str) -> Answers {
+    let mut parts = answer_candidates(page)
+        .into_iter()
+        .map(|mut candidates| candidates.pop());
+    Answers {
+        a: parts.next().flatten(),
+        b: parts.next().flatten(),
+    }
+}
+
+/// Drops every tag from an HTML fragment and decodes the entities left in the text.
+pub fn text(fragment: &str) -> String {
+    let mut stripped = String::with_capacity(fragment.len());
+    let mut rest = fragment;
+    while let Some(start) = rest.find('<') {
+        stripped.push_str(&rest[..start]);
+        rest = match rest[start..].find('>') {
+            Some(end) => &rest[start + end + 1..],
+            None => "",
+        };
+    }
+    stripped.push_str(rest);
+    decode_entities(&stripped)
+}
+
+/// Decodes the named entities puzzle pages use plus numeric ones, leaving unknown ones as written.
+pub fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest
+            .find(';')
+            .filter(|&end| end <= 10)
+            .and_then(|end| Some((entity(&rest[1..end])?, end)));
+        match entity {
+            Some((ch, end)) => {
+                decoded.push(ch);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+fn entity(name: &str) -> Option<char> {
+    match name {
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "amp" => Some('&'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => {
+            let code = match name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => name.strip_prefix('#')?.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY1: &str = include_str!("../tests/fixtures/day1.html");
+    const DAY15: &str = include_str!("../tests/fixtures/day15.html");
+
+    #[test]
+    fn finds_the_example() {
+        let blocks = code_blocks(DAY1);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(
+            blocks[0].trim_end(),
+            include_str!("../tests/fixtures/day1.txt").trim_end()
+        );
+    }
+
+    #[test]
+    fn decodes_and_strips_blocks() {
+        let blocks = code_blocks(DAY15);
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(blocks[0], include_str!("../tests/fixtures/day15.txt"));
+        assert_eq!(
+            blocks[1],
+            include_str!("../tests/fixtures/day15_simple.txt")
+        );
+        assert!(blocks[2].contains("##@.O..#\n"));
+        assert!(blocks[2].contains("Move <:"));
+    }
+
+    #[test]
+    fn joins_chosen_blocks() -> anyhow::Result<()> {
+        let blocks = ["1\n2\n", "3"].map(String::from);
+        assert_eq!(sample_from(&blocks, &[1, 0])?, "3\n\n1\n2\n");
+        assert!(sample_from(&blocks, &[2]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn proposes_the_last_candidate() {
+        assert_eq!(answer_candidates(DAY1), [vec!["2", "11"], vec!["31"]]);
+        assert_eq!(
+            proposed_sample_answers(DAY1),
+            Answers {
+                a: Some("11".to_string()),
+                b: Some("31".to_string()),
+            }
+        );
+        assert_eq!(proposed_sample_answers(DAY15).a.as_deref(), Some("10092"));
+        assert_eq!(proposed_sample_answers(DAY15).b, None);
+    }
+
+    #[test]
+    fn leaves_unknown_entities() {
+        assert_eq!(
+            decode_entities("a &amp;&amp b &#60;&#x3e; &bogus;"),
+            "a &&amp b <> &bogus;"
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::dimensions_2::grid::RaggedRow;
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+/// Why part of the input was rejected.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Reason {
+    /// Something the puzzle always has, e.g. the second number of a pair, ran out.
+    Missing(&'static str),
+    /// The text doesn't read as the value it should be.
+    Invalid(&'static str),
+    /// A character the puzzle doesn't use.
+    UnknownChar(char),
+    /// Valid on its own but not here, e.g. a second robot.
+    Unexpected(&'static str),
+    /// A grid row that isn't as wide as the first.
+    RaggedRow { width: usize, expected: usize },
+}
+
+/// Where a puzzle input stopped making sense, with 1-based line and column in characters.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    /// The offending text, or for [`Reason::Missing`] the text it should have followed, if any.
+    pub text: String,
+    pub reason: Reason,
+}
+
+impl ParseError {
+    /// Locates `text`, a slice of `input` such as a line or a field split off one.
+    pub fn at(input: &str, text: &str, reason: Reason) -> Self {
+        let (line, column) = location(input, text);
+        Self {
+            line,
+            column,
+            text: text.to_string(),
+            reason,
+        }
+    }
+
+    /// Points just past `after`, a slice of `input`, where `what` should have been.
+    pub fn missing(input: &str, after: &str, what: &'static str) -> Self {
+        let (line, column) = location(input, after);
+        Self {
+            line,
+            column: column + after.chars().count(),
+            text: after.to_string(),
+            reason: Reason::Missing(what),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match &self.reason {
+            Reason::Missing(what) if self.text.is_empty() => write!(f, "expected {what}"),
+            Reason::Missing(what) => write!(f, "expected {what} after `{}`", self.text),
+            Reason::Invalid(what) => write!(f, "`{}` is not {what}", self.text),
+            Reason::UnknownChar(ch) => write!(f, "unknown character `{ch}`"),
+            Reason::Unexpected(what) => write!(f, "unexpected {what} `{}`", self.text),
+            Reason::RaggedRow { width, expected } => {
+                write!(f, "row is {width} wide, expected {expected}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Points at the end of the shorter of the row and the first, assuming one grid row per line.
+impl From<RaggedRow> for ParseError {
+    fn from(ragged: RaggedRow) -> Self {
+        Self {
+            line: ragged.row + 1,
+            column: ragged.width.min(ragged.expected) + 1,
+            text: String::new(),
+            reason: Reason::RaggedRow {
+                width: ragged.width,
+                expected: ragged.expected,
+            },
+        }
+    }
+}
+
+/// Line and column of `text` within `input`, found by its address when it is a slice of `input`.
+fn location(input: &str, text: &str) -> (usize, usize) {
+    let offset = (text.as_ptr() as usize)
+        .checked_sub(input.as_ptr() as usize)
+        .filter(|&offset| offset + text.len() <= input.len())
+        .or_else(|| input.find(text))
+        .unwrap_or_default();
+    let before = input.get(..offset).unwrap_or_default();
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .rsplit('\n')
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .count()
+        + 1;
+    (line, column)
+}
+
+/// Parses `text`, a slice of `input`, as `what`, e.g. `"a number"`.
+pub fn value<T: FromStr>(input: &str, text: &str, what: &'static str) -> ParseResult<T> {
+    text.parse()
+        .map_err(|_| ParseError::at(input, text, Reason::Invalid(what)))
+}
+
+/// Parses `text`, a slice of `input`, as a number.
+pub fn number<T: FromStr>(input: &str, text: &str) -> ParseResult<T> {
+    value(input, text, "a number")
+}
+
+/// Takes the next field of `fields`, split off `line`, reporting what was missing after `line`.
+pub fn next<'a>(
+    input: &str,
+    line: &'a str,
+    fields: &mut impl Iterator<Item = &'a str>,
+    what: &'static str,
+) -> ParseResult<&'a str> {
+    fields
+        .next()
+        .ok_or_else(|| ParseError::missing(input, line, what))
+}
+
+/// Each character of each line with the slice of `input` it came from, for reporting it.
+pub fn chars(
+    input: &str,
+) -> impl Iterator<Item = (usize, impl Iterator<Item = (usize, char, &str)>)> {
+    input.lines().enumerate().map(|(y, line)| {
+        (
+            y,
+            line.char_indices()
+                .enumerate()
+                .map(move |(x, (start, ch))| (x, ch, &line[start..start + ch.len_utf8()])),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_slices() {
+        let input = "3   4\n4   x\n";
+        let line = input.lines().nth(1).unwrap();
+        let field = line.split("   ").nth(1).unwrap();
+        let err = number::<u64>(input, field).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+        assert_eq!(err.to_string(), "line 2, column 5: `x` is not a number");
+        let err = next(input, line, &mut line.split(',').skip(1), "a comma").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 6: expected a comma after `4   x`"
+        );
+    }
+
+    #[test]
+    fn reports_unknown_chars() {
+        let input = "..\n.é#\n";
+        let err = chars(input)
+            .flat_map(|(_, line)| line)
+            .find(|&(_, ch, _)| ch != '.')
+            .map(|(_, ch, text)| ParseError::at(input, text, Reason::UnknownChar(ch)))
+            .unwrap();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.to_string(), "line 2, column 2: unknown character `é`");
+    }
+}
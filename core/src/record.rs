@@ -0,0 +1,146 @@
+use serde::Serialize;
+
+use crate::{
+    cli::Format,
+    runner::{DayRun, Part, PartRun},
+};
+
+/// One part of one run, flattened so runs can be aggregated across days and commits.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct RunRecord {
+    pub year: u16,
+    pub day: u8,
+    pub part: &'static str,
+    pub answer: Option<String>,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+    /// Both parts came from one [`Solution::solve_both`](crate::Solution::solve_both) pass, so
+    /// each part's `solve_ns` is the time of both.
+    pub solved_together: bool,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+impl RunRecord {
+    pub const CSV_HEADER: &'static str =
+        "year,day,part,answer,parse_ns,solve_ns,solved_together,success,error";
+
+    pub fn from_run(run: &DayRun) -> Vec<Self> {
+        let together = run
+            .a
+            .as_ref()
+            .filter(|_| run.solved_together)
+            .map(|a| a.elapsed);
+        [("a", &run.a), ("b", &run.b)]
+            .into_iter()
+            .filter_map(|(part, part_run)| Some((part, part_run.as_ref()?)))
+            .map(|(part, PartRun { answer, elapsed })| Self {
+                year: run.year,
+                day: run.day,
+                part,
+                answer: answer.as_ref().ok().cloned(),
+                parse_ns: run.parse.as_nanos() as u64,
+                solve_ns: together.unwrap_or(*elapsed).as_nanos() as u64,
+                solved_together: run.solved_together,
+                success: answer.is_ok(),
+                error: answer.as_ref().err().cloned(),
+            })
+            .collect()
+    }
+
+    /// Records for a day that failed before any part ran, e.g. on a missing input or a bad parse.
+    pub fn failed(year: u16, day: u8, part: Part, error: &anyhow::Error) -> Vec<Self> {
+        [("a", part.includes_a()), ("b", part.includes_b())]
+            .into_iter()
+            .filter(|(_, included)| *included)
+            .map(|(part, _)| Self {
+                year,
+                day,
+                part,
+                answer: None,
+                parse_ns: 0,
+                solve_ns: 0,
+                solved_together: false,
+                success: false,
+                error: Some(format!("{error:#}")),
+            })
+            .collect()
+    }
+
+    pub fn to_csv(&self) -> String {
+        [
+            self.year.to_string(),
+            self.day.to_string(),
+            self.part.to_string(),
+            csv_field(self.answer.as_deref().unwrap_or_default()),
+            self.parse_ns.to_string(),
+            self.solve_ns.to_string(),
+            self.solved_together.to_string(),
+            self.success.to_string(),
+            csv_field(self.error.as_deref().unwrap_or_default()),
+        ]
+        .join(",")
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Prints records as JSON lines or CSV, writing the CSV header before the first record only.
+#[derive(Debug)]
+pub struct RecordWriter {
+    format: Format,
+    header_written: bool,
+}
+
+impl RecordWriter {
+    pub fn new(format: Format) -> Self {
+        Self {
+            format,
+            header_written: false,
+        }
+    }
+
+    pub fn write(&mut self, records: &[RunRecord]) -> anyhow::Result<()> {
+        for record in records {
+            match self.format {
+                Format::Json => println!("{}", serde_json::to_string(record)?),
+                Format::Csv => {
+                    if !self.header_written {
+                        println!("{}", RunRecord::CSV_HEADER);
+                        self.header_written = true;
+                    }
+                    println!("{}", record.to_csv());
+                }
+                Format::Text => anyhow::bail!("Records are only written as json or csv"),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_quotes_when_needed() {
+        let record = RunRecord {
+            year: 2024,
+            day: 1,
+            part: "a",
+            answer: Some("1,2".to_string()),
+            parse_ns: 3,
+            solve_ns: 4,
+            solved_together: false,
+            success: true,
+            error: None,
+        };
+        assert_eq!(record.to_csv(), "2024,1,a,\"1,2\",3,4,false,true,");
+    }
+}
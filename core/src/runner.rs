@@ -0,0 +1,111 @@
+use std::{
+    fmt::Display,
+    path::Path,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use anyhow::bail;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Part {
+    A,
+    B,
+    #[default]
+    Both,
+}
+
+impl Part {
+    pub fn includes_a(self) -> bool {
+        matches!(self, Part::A | Part::Both)
+    }
+
+    pub fn includes_b(self) -> bool {
+        matches!(self, Part::B | Part::Both)
+    }
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" => Ok(Part::A),
+            "b" => Ok(Part::B),
+            "both" => Ok(Part::Both),
+            _ => bail!("Unknown part `{s}`, expected a, b or both"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PartRun {
+    /// The answer, or the rendered error when the part failed.
+    pub answer: Result<String, String>,
+    pub elapsed: Duration,
+}
+
+impl PartRun {
+    pub fn timed<A: Display>(f: impl FnOnce() -> anyhow::Result<A>) -> Self {
+        let (answer, elapsed) = timed(f);
+        Self {
+            answer: answer
+                .map(|answer| answer.to_string())
+                .map_err(|err| format!("{err:#}")),
+            elapsed,
+        }
+    }
+}
+
+impl Display for PartRun {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.answer {
+            Ok(answer) => write!(f, "{answer}"),
+            Err(err) => write!(f, "error: {err}"),
+        }
+    }
+}
+
+/// Answers and timings of a single day, only the requested parts are filled in.
+#[derive(Clone, Debug)]
+pub struct DayRun {
+    pub year: u16,
+    pub day: u8,
+    pub parse: Duration,
+    pub a: Option<PartRun>,
+    pub b: Option<PartRun>,
+    /// Both parts came from one [`Solution::solve_both`](crate::Solution::solve_both) pass, its
+    /// time is on part a and part b shows none.
+    pub solved_together: bool,
+}
+
+impl DayRun {
+    pub fn is_success(&self) -> bool {
+        [&self.a, &self.b]
+            .into_iter()
+            .flatten()
+            .all(|part| part.answer.is_ok())
+    }
+}
+
+/// A day made available to the `aoc` runner by the `aoc!` macro.
+pub struct RegisteredDay {
+    pub year: u16,
+    pub day: u8,
+    pub run: fn(Option<&Path>, Part) -> anyhow::Result<DayRun>,
+}
+
+inventory::collect!(RegisteredDay);
+
+/// Every registered day ordered by year then day.
+pub fn registered_days() -> Vec<&'static RegisteredDay> {
+    let mut days: Vec<_> = inventory::iter::<RegisteredDay>.into_iter().collect();
+    days.sort_by_key(|day| (day.year, day.day));
+    days
+}
+
+pub fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let ans = f();
+    (ans, start.elapsed())
+}
@@ -0,0 +1,291 @@
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use anyhow::{bail, Context};
+use itertools::Itertools;
+
+use crate::{answers::Answers, cache::Cache};
+
+/// How the generated `parse` reads the input, after the shapes the earlier days settled on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Template {
+    /// One `&str` per line.
+    Lines,
+    /// A [`Grid`](crate::dimensions_2::grid::Grid) borrowing the input's bytes, like days 4 and 12.
+    Grid,
+    /// Whitespace-separated numbers on each line, like days 1 and 2.
+    Numbers,
+    /// Blank-line-separated blocks of lines, like days 5, 13 and 15.
+    Blocks,
+}
+
+impl Template {
+    /// Picks the template matching the shape of a sample.
+    pub fn detect(sample: &str) -> Self {
+        let sample = sample.trim_end();
+        let lines = sample.lines().collect_vec();
+        if sample.contains("\n\n") {
+            Template::Blocks
+        } else if !lines.is_empty()
+            && lines.iter().all(|line| {
+                line.split_whitespace()
+                    .all(|word| word.parse::<i64>().is_ok())
+                    && !line.trim().is_empty()
+            })
+        {
+            Template::Numbers
+        } else if lines.len() > 1
+            && lines.iter().map(|line| line.len()).all_equal()
+            && !sample.contains(' ')
+        {
+            Template::Grid
+        } else {
+            Template::Lines
+        }
+    }
+
+    fn imports(self) -> &'static str {
+        match self {
+            Template::Grid => "use dimensions_2::grid::Grid;\n",
+            _ => "",
+        }
+    }
+
+    fn parsed(self) -> &'static str {
+        match self {
+            Template::Lines => "Vec<&'input str>",
+            Template::Grid => "Grid<u8, &'input [u8]>",
+            Template::Numbers => "Vec<Vec<u64>>",
+            Template::Blocks => "Vec<Vec<&'input str>>",
+        }
+    }
+
+    fn parse_body(self) -> &'static str {
+        match self {
+            Template::Lines => "        Ok(input.lines().collect())",
+            Template::Grid => "        Ok(Grid::from_lines(input)?)",
+            Template::Numbers => {
+                "        input
+            .lines()
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|num| parse::number(input, num))
+                    .collect()
+            })
+            .collect()"
+            }
+            Template::Blocks => {
+                "        Ok(input
+            .split(\"\\n\\n\")
+            .map(|block| block.lines().collect())
+            .collect())"
+            }
+        }
+    }
+}
+
+impl FromStr for Template {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "lines" => Ok(Template::Lines),
+            "grid" => Ok(Template::Grid),
+            "numbers" => Ok(Template::Numbers),
+            "blocks" => Ok(Template::Blocks),
+            _ => bail!("Unknown template `{s}`, expected lines, grid, numbers or blocks"),
+        }
+    }
+}
+
+/// Source of a new day wired to `Solution`, with the sample answers filled in when known.
+pub fn main_rs(year: u16, day: u8, template: Template, sample_answers: &Answers) -> String {
+    let answer = |answer: &Option<String>| {
+        answer
+            .as_deref()
+            .filter(|answer| answer.parse::<usize>().is_ok())
+            .unwrap_or("0")
+            .to_string()
+    };
+    format!(
+        "use aoc_{year}::*;
+{imports}
+aoc!(Day{day});
+
+impl Solution<Self> for Day{day} {{
+    type Parsed<'input> = {parsed};
+
+    type AnswerA = usize;
+
+    type AnswerB = usize;
+
+    type Config = ();
+
+    const CONFIG: Self::Config = ();
+
+    const SAMPLE_CONFIG: Self::Config = ();
+
+    const SAMPLE_ANSWER_A: Self::AnswerA = {answer_a};
+
+    const SAMPLE_ANSWER_B: Self::AnswerB = {answer_b};
+
+    fn parse(input: &str) -> ParseResult<Self::Parsed<'_>> {{
+{parse_body}
+    }}
+
+    fn part_a(_input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerA> {{
+        anyhow::bail!(\"Part a is not solved yet\")
+    }}
+
+    fn part_b(_input: &Self::Parsed<'_>, _: &Self::Config) -> anyhow::Result<Self::AnswerB> {{
+        anyhow::bail!(\"Part b is not solved yet\")
+    }}
+}}
+",
+        imports = template.imports(),
+        parsed = template.parsed(),
+        answer_a = answer(&sample_answers.a),
+        answer_b = answer(&sample_answers.b),
+        parse_body = template.parse_body(),
+    )
+}
+
+/// Adds `day` to the modules a year's library compiles in for the `aoc` runner, keeping them
+/// sorted.
+pub fn register_day(lib: &str, day: u8) -> anyhow::Result<String> {
+    let start = lib
+        .find("mod days {\n")
+        .context("No `mod days` in the year's lib.rs")?
+        + "mod days {\n".len();
+    let end = start
+        + lib[start..]
+            .find("\n}")
+            .context("`mod days` in the year's lib.rs is not closed")?;
+    let name = format!("day{day}");
+    let mut days = lib[start..end]
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("mod ")?.strip_suffix(';'))
+        .map(String::from)
+        .collect_vec();
+    if days.contains(&name) {
+        return Ok(lib.to_string());
+    }
+    days.push(name);
+    days.sort();
+    let modules = days
+        .iter()
+        .map(|day| format!("    #[path = \"{day}/main.rs\"]\n    mod {day};"))
+        .join("\n");
+    Ok(format!("{}{modules}{}", &lib[..start], &lib[end..]))
+}
+
+/// Creates `src/bin/day<N>` in the year's crate at `manifest_dir` and registers it in its `lib.rs`.
+///
+/// The sample, its proposed answers and the input are copied from the cache when it has them,
+/// otherwise `sample.txt` starts empty. Returns the files written.
+pub fn scaffold(
+    manifest_dir: &Path,
+    year: u16,
+    day: u8,
+    template: Option<Template>,
+    cache: Option<&Cache>,
+) -> anyhow::Result<Vec<PathBuf>> {
+    if !manifest_dir.join("Cargo.toml").is_file() {
+        bail!("No crate for {year} at {}", manifest_dir.display());
+    }
+    let bin_dir = manifest_dir.join("src").join("bin");
+    let day_dir = bin_dir.join(format!("day{day}"));
+    let main_path = day_dir.join("main.rs");
+    if main_path.exists() {
+        bail!("{} already exists", main_path.display());
+    }
+    let cached = |name: &str| {
+        cache
+            .map(|cache| cache.day_dir(year, day).join(name))
+            .filter(|path| path.is_file())
+    };
+
+    let sample = match cached("sample.txt") {
+        Some(path) => std::fs::read_to_string(&path)
+            .with_context(|| format!("Could not read {}", path.display()))?,
+        None => String::new(),
+    };
+    let sample_answers = match cached("sample_answers.toml") {
+        Some(path) => Answers::load(&path)?,
+        None => Answers::default(),
+    };
+    let template = template.unwrap_or_else(|| Template::detect(&sample));
+
+    std::fs::create_dir_all(&day_dir)
+        .with_context(|| format!("Could not create {}", day_dir.display()))?;
+    let mut written = vec![
+        (main_path, main_rs(year, day, template, &sample_answers)),
+        (day_dir.join("sample.txt"), sample),
+    ];
+    if sample_answers != Answers::default() {
+        written.push((
+            day_dir.join("sample_answers.toml"),
+            toml::to_string(&sample_answers)?,
+        ));
+    }
+    if let Some(path) = cached("input.txt") {
+        let input = std::fs::read_to_string(&path)
+            .with_context(|| format!("Could not read {}", path.display()))?;
+        written.push((day_dir.join("input.txt"), input));
+    }
+    let lib_path = manifest_dir.join("src").join("lib.rs");
+    let lib = std::fs::read_to_string(&lib_path)
+        .with_context(|| format!("Could not read {}", lib_path.display()))?;
+    written.push((lib_path, register_day(&lib, day)?));
+
+    for (path, contents) in &written {
+        std::fs::write(path, contents)
+            .with_context(|| format!("Could not write {}", path.display()))?;
+    }
+    Ok(written.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_earlier_shapes() {
+        let detect = |sample: &str| Template::detect(sample);
+        assert_eq!(
+            detect(include_str!("../tests/fixtures/day1.txt")),
+            Template::Numbers
+        );
+        assert_eq!(
+            detect("MMMSXXMASM\nMSAMXMSMSA\nAMXSXMAAMM\n"),
+            Template::Grid
+        );
+        assert_eq!(detect("190: 10 19\n3267: 81 40 27\n"), Template::Lines);
+        assert_eq!(
+            detect(include_str!("../tests/fixtures/day15.txt")),
+            Template::Blocks
+        );
+    }
+
+    #[test]
+    fn grid_template_borrows_the_input() {
+        let main = main_rs(2024, 20, Template::Grid, &Answers::default());
+        assert!(main.starts_with("use aoc_2024::*;\nuse dimensions_2::grid::Grid;\n\naoc!(Day20);"));
+        assert!(main.contains("type Parsed<'input> = Grid<u8, &'input [u8]>;"));
+        assert!(main.contains("Ok(Grid::from_lines(input)?)"));
+    }
+
+    #[test]
+    fn registers_in_order() -> anyhow::Result<()> {
+        let runner = "mod days {\n    #[path = \"day1/main.rs\"]\n    mod day1;\n    #[path = \"day2/main.rs\"]\n    mod day2;\n}\n";
+        let registered = register_day(runner, 17)?;
+        assert_eq!(
+            registered,
+            "mod days {\n    #[path = \"day1/main.rs\"]\n    mod day1;\n    #[path = \"day17/main.rs\"]\n    mod day17;\n    #[path = \"day2/main.rs\"]\n    mod day2;\n}\n"
+        );
+        assert_eq!(register_day(&registered, 17)?, registered);
+        Ok(())
+    }
+}
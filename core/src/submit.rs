@@ -0,0 +1,335 @@
+use std::{
+    fmt::Display,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{bail, Context};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::{cache::Fetcher, page, runner::Part};
+
+/// The wait after a wrong answer when the reply doesn't say, the site's shortest.
+const WRONG_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// Posts answers to the site, [`Fetcher`] for adventofcode.com or whatever its base URL points at.
+pub trait Client {
+    /// Returns the page the site replied with.
+    fn post_answer(&self, year: u16, day: u8, part: Part, answer: &str) -> anyhow::Result<String>;
+}
+
+impl Client for Fetcher {
+    fn post_answer(&self, year: u16, day: u8, part: Part, answer: &str) -> anyhow::Result<String> {
+        self.answer(year, day, level(part)?, answer)
+    }
+}
+
+fn level(part: Part) -> anyhow::Result<u8> {
+    match part {
+        Part::A => Ok(1),
+        Part::B => Ok(2),
+        Part::Both => bail!("Answers are submitted one part at a time"),
+    }
+}
+
+fn tag(part: Part) -> &'static str {
+    match part {
+        Part::A => "a",
+        Part::B => "b",
+        Part::Both => "both",
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong without a hint, or with a hint this doesn't recognise.
+    Wrong,
+    /// Submitted too soon after the last answer, nothing was checked.
+    Wait,
+    /// The part was already solved or isn't unlocked yet, nothing was checked.
+    WrongLevel,
+}
+
+impl Verdict {
+    fn is_wrong(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+            Verdict::Wait => "too soon",
+            Verdict::WrongLevel => "for the wrong level",
+        })
+    }
+}
+
+/// What the site made of an answer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reply {
+    pub verdict: Verdict,
+    /// How long until the site takes another answer.
+    pub cooldown: Option<Duration>,
+    /// The reply's text, without markup.
+    pub message: String,
+}
+
+/// Reads the reply out of the `<article>` of the page returned for an answer.
+pub fn parse_reply(page: &str) -> anyhow::Result<Reply> {
+    let article = page
+        .split_once("<article>")
+        .and_then(|(_, rest)| Some(rest.split_once("</article>")?.0))
+        .context("The reply has no <article>")?;
+    let message = page::text(article).split_whitespace().join(" ");
+    let verdict = if message.starts_with("That's the right answer") {
+        Verdict::Correct
+    } else if message.contains("your answer is too high") {
+        Verdict::TooHigh
+    } else if message.contains("your answer is too low") {
+        Verdict::TooLow
+    } else if message.starts_with("That's not the right answer") {
+        Verdict::Wrong
+    } else if message.contains("left to wait") {
+        Verdict::Wait
+    } else if message.contains("solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        bail!("Unrecognised reply: {message}");
+    };
+    let cooldown = match verdict {
+        Verdict::Wait => Some(left_to_wait(&message)?),
+        verdict if verdict.is_wrong() => Some(wrong_cooldown(&message)),
+        _ => None,
+    };
+    Ok(Reply {
+        verdict,
+        cooldown,
+        message,
+    })
+}
+
+/// Reads `You have 1m 30s left to wait`.
+fn left_to_wait(message: &str) -> anyhow::Result<Duration> {
+    let left = message
+        .split_once("You have ")
+        .and_then(|(_, rest)| Some(rest.split_once(" left to wait")?.0))
+        .with_context(|| format!("No wait in the reply: {message}"))?;
+    left.split_whitespace()
+        .try_fold(Duration::ZERO, |total, amount| {
+            let (count, unit) = amount.split_at(amount.len().saturating_sub(1));
+            let seconds = match unit {
+                "h" => 3600,
+                "m" => 60,
+                "s" => 1,
+                _ => bail!("Unknown wait `{left}`"),
+            };
+            let count: u64 = count
+                .parse()
+                .with_context(|| format!("Unknown wait `{left}`"))?;
+            Ok(total + Duration::from_secs(count * seconds))
+        })
+}
+
+/// Reads `Please wait one minute` or `please wait 5 minutes` after a wrong answer.
+fn wrong_cooldown(message: &str) -> Duration {
+    let message = message.to_lowercase();
+    let minutes = message
+        .split_once("please wait ")
+        .and_then(|(_, rest)| rest.split_whitespace().next())
+        .and_then(|count| match count {
+            "one" => Some(1),
+            count => count.parse().ok(),
+        });
+    minutes.map_or(WRONG_COOLDOWN, |minutes| Duration::from_secs(minutes * 60))
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Attempt {
+    pub part: String,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch.
+    pub at: u64,
+    /// When the site takes another answer, in seconds since the Unix epoch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_at: Option<u64>,
+}
+
+/// Every answer submitted for a day, oldest first.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct History {
+    #[serde(default, rename = "attempt")]
+    pub attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Loads the history at `path`, a missing file holds no attempts yet.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(file) => toml::from_str(&file)
+                .with_context(|| format!("Could not parse history {}", path.display())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => {
+                Err(err).with_context(|| format!("Could not read history {}", path.display()))
+            }
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        crate::cache::write_atomically(path, &toml::to_string(self)?)
+    }
+
+    /// Refuses an answer the history already rules out: while a cooldown is running, once the part
+    /// is solved, or when it was wrong before or lies beyond a number that was too high or too low.
+    pub fn check(&self, part: Part, answer: &str, now: u64) -> anyhow::Result<()> {
+        if let Some(retry_at) = self
+            .attempts
+            .iter()
+            .filter_map(|attempt| attempt.retry_at)
+            .max()
+            .filter(|&retry_at| retry_at > now)
+        {
+            bail!("The site takes another answer in {}s", retry_at - now);
+        }
+        let tag = tag(part);
+        let number = answer.parse::<i128>().ok();
+        for attempt in self.attempts.iter().filter(|attempt| attempt.part == tag) {
+            let tried = attempt.answer.parse::<i128>().ok();
+            match attempt.verdict {
+                Verdict::Correct => bail!("Part {tag} was already solved with {}", attempt.answer),
+                verdict if verdict.is_wrong() && attempt.answer == answer => {
+                    bail!("{answer} was already submitted for part {tag} and was {verdict}")
+                }
+                Verdict::TooHigh if number >= tried && tried.is_some() => bail!(
+                    "{answer} is not below {}, which was too high for part {tag}",
+                    attempt.answer
+                ),
+                Verdict::TooLow if number <= tried && number.is_some() => bail!(
+                    "{answer} is not above {}, which was too low for part {tag}",
+                    attempt.answer
+                ),
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Submits `answer` unless the history at `history_path` rules it out, then records the attempt
+/// and the site's reply there.
+pub fn submit(
+    client: &impl Client,
+    history_path: &Path,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> anyhow::Result<Reply> {
+    let mut history = History::load(history_path)?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .context("The clock is before 1970")?
+        .as_secs();
+    history.check(part, answer, now)?;
+    let reply = parse_reply(&client.post_answer(year, day, part, answer)?)?;
+    history.attempts.push(Attempt {
+        part: tag(part).to_string(),
+        answer: answer.to_string(),
+        verdict: reply.verdict,
+        at: now,
+        retry_at: reply.cooldown.map(|cooldown| now + cooldown.as_secs()),
+    });
+    history.save(history_path)?;
+    Ok(reply)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stand_in::stand_in;
+
+    const TOO_HIGH: &str = "<main>\n<article><p>That's not the right answer; your answer is too high.  \
+        If you're stuck, make sure you're using the full input data.  Please wait one minute before \
+        trying again. <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article>\n</main>";
+    const CORRECT: &str = "<main>\n<article><p>That's the right answer!  You are <span class=\"day-success\">one \
+        gold star</span> closer to finding the Chief Historian. <a href=\"/2024/day/1#part2\">[Continue to \
+        Part Two]</a></p></article>\n</main>";
+
+    #[test]
+    fn reads_replies() -> anyhow::Result<()> {
+        let reply = parse_reply(TOO_HIGH)?;
+        assert_eq!(reply.verdict, Verdict::TooHigh);
+        assert_eq!(reply.cooldown, Some(Duration::from_secs(60)));
+        assert_eq!(parse_reply(CORRECT)?.verdict, Verdict::Correct);
+        let wait = parse_reply(
+            "<article><p>You gave an answer too recently; you have to wait after submitting an \
+             answer before trying again.  You have 4m 21s left to wait.</p></article>",
+        )?;
+        assert_eq!(wait.verdict, Verdict::Wait);
+        assert_eq!(wait.cooldown, Some(Duration::from_secs(261)));
+        assert_eq!(
+            wrong_cooldown(
+                "That's not the right answer. Please wait 5 minutes before trying again."
+            ),
+            Duration::from_secs(300)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn refuses_ruled_out_answers() {
+        let attempt = |answer: &str, verdict, retry_at| Attempt {
+            part: "a".to_string(),
+            answer: answer.to_string(),
+            verdict,
+            at: 0,
+            retry_at,
+        };
+        let history = History {
+            attempts: vec![
+                attempt("100", Verdict::TooHigh, Some(60)),
+                attempt("10", Verdict::TooLow, None),
+                attempt("abc", Verdict::Wrong, None),
+            ],
+        };
+        assert!(history.check(Part::A, "50", 59).is_err());
+        assert!(history.check(Part::A, "50", 60).is_ok());
+        assert!(history.check(Part::A, "100", 60).is_err());
+        assert!(history.check(Part::A, "120", 60).is_err());
+        assert!(history.check(Part::A, "9", 60).is_err());
+        assert!(history.check(Part::A, "abc", 60).is_err());
+        assert!(history.check(Part::A, "abd", 60).is_ok());
+        assert!(history.check(Part::B, "100", 60).is_ok());
+    }
+
+    #[test]
+    fn records_attempts() -> anyhow::Result<()> {
+        let (url, server) = stand_in(vec![(200, TOO_HIGH)]);
+        let fetcher = Fetcher::new(url, "secret");
+        let dir = std::env::temp_dir().join(format!("aoc-submit-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join("submissions.toml");
+        let reply = submit(&fetcher, &path, 2024, 1, Part::B, "42")?;
+        assert_eq!(reply.verdict, Verdict::TooHigh);
+        let history = History::load(&path)?;
+        assert_eq!(history.attempts.len(), 1);
+        assert_eq!(history.attempts[0].answer, "42");
+        // Still cooling down, and the stand-in has stopped listening.
+        assert!(submit(&fetcher, &path, 2024, 1, Part::B, "41").is_err());
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2024/day/1/answer "));
+        assert!(requests[0].contains("session=secret"));
+        assert!(requests[0].ends_with("level=2&answer=42"));
+        Ok(())
+    }
+}